@@ -0,0 +1,83 @@
+use std::{fmt, fs, io, path::Path};
+
+use anyhow::Context;
+use serde_json::Value;
+
+use crate::fetch::{field, keys_path};
+
+/// How an answer compares to the one the site accepted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail(String),
+    Unknown,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail(expected) => write!(f, "FAIL (expected {expected})"),
+            Verdict::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+/// The accepted answers for a quest. The site adds an `answerN` to the keys once part N is solved.
+#[derive(Debug, Default)]
+pub struct Answers([Option<String>; 3]);
+
+impl Answers {
+    /// Load the answers from the keys file in `dir`. No keys file just means we don't know any
+    /// of the answers yet.
+    pub fn load(dir: &Path, quest: u8) -> anyhow::Result<Self> {
+        let path = keys_path(dir, quest);
+        let keys = match fs::read_to_string(&path) {
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            r => r.with_context(|| format!("couldn't read {}", path.display()))?,
+        };
+        Self::parse(&keys).with_context(|| format!("couldn't parse {}", path.display()))
+    }
+
+    pub fn parse(keys: &str) -> anyhow::Result<Self> {
+        let keys: Value = serde_json::from_str(keys)?;
+        Ok(Self(
+            [1, 2, 3].map(|part| field(&keys, &format!("answer{part}")).ok()),
+        ))
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        self.0.get(part as usize - 1)?.as_deref()
+    }
+
+    pub fn check(&self, part: u8, value: &str) -> Verdict {
+        match self.get(part) {
+            Some(expected) if expected == value => Verdict::Pass,
+            Some(expected) => Verdict::Fail(expected.to_string()),
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() -> anyhow::Result<()> {
+        let answers = Answers::parse(r#"{"key1":"abc","answer1":"42","answer2":17}"#)?;
+        assert_eq!(answers.check(1, "42"), Verdict::Pass);
+        assert_eq!(answers.check(2, "17"), Verdict::Pass);
+        assert_eq!(answers.check(1, "41"), Verdict::Fail("42".to_string()));
+        assert_eq!(answers.check(3, "1"), Verdict::Unknown);
+        assert_eq!(answers.check(1, "41").to_string(), "FAIL (expected 42)");
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_missing() -> anyhow::Result<()> {
+        let answers = Answers::load(&std::env::temp_dir().join("ec-no-keys"), 1)?;
+        assert_eq!(answers.check(1, "1"), Verdict::Unknown);
+        Ok(())
+    }
+}
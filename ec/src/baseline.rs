@@ -0,0 +1,174 @@
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use anyhow::{Context, anyhow};
+use ec_common::solution::{crate_name, event_name};
+use serde_json::Value;
+
+use crate::bench::Bench;
+
+/// Baselines are saved next to each event's crate (e.g. `ec_2025/baselines`).
+pub fn default_dir(event: u16) -> PathBuf {
+    crate::workspace_root()
+        .join(crate_name(event))
+        .join("baselines")
+}
+
+/// Every part of a quest shares a baseline file (e.g. `quest12.json`).
+pub fn path(dir: &Path, quest: u8) -> PathBuf {
+    dir.join(format!("quest{quest:02}.json"))
+}
+
+/// Load the baselines for every part of a quest. A missing file just means there aren't any yet.
+pub fn load(dir: &Path, quest: u8) -> anyhow::Result<Vec<Bench>> {
+    let path = path(dir, quest);
+    let json = match fs::read_to_string(&path) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        r => r.with_context(|| format!("couldn't read {}", path.display()))?,
+    };
+
+    let json: Value = serde_json::from_str(&json)?;
+    json.as_array()
+        .into_iter()
+        .flatten()
+        .map(|b| Bench::from_json(b).ok_or_else(|| anyhow!("bad baseline in {}", path.display())))
+        .collect()
+}
+
+/// Save `results` as the new baselines. Parts that weren't run keep their old baseline.
+pub fn save(dir: &Path, results: &[Bench]) -> anyhow::Result<()> {
+    fs::create_dir_all(dir)?;
+
+    let mut quests = results.iter().map(|b| b.quest).collect::<Vec<_>>();
+    quests.dedup();
+    for quest in quests {
+        let mut baselines = load(dir, quest)?;
+        baselines.retain(|old| {
+            !results
+                .iter()
+                .any(|b| b.quest == quest && b.part == old.part)
+        });
+        baselines.extend(results.iter().filter(|b| b.quest == quest).cloned());
+        baselines.sort_by_key(|b| b.part);
+        fs::write(path(dir, quest), crate::bench::to_json(&baselines) + "\n")?;
+    }
+    Ok(())
+}
+
+/// How a part's median time compares to its baseline.
+#[derive(Debug, Clone)]
+pub struct Comparison {
+    pub event: u16,
+    pub quest: u8,
+    pub part: u8,
+    pub baseline: Duration,
+    pub current: Duration,
+    /// The percentage the current run is slower (or faster when negative) than the baseline.
+    pub change: f64,
+    pub regressed: bool,
+}
+
+impl Comparison {
+    /// Compare `current` to `baseline`, flagging it when it's more than `threshold` percent slower.
+    pub fn new(current: &Bench, baseline: &Bench, threshold: f64) -> Self {
+        let (before, after) = (baseline.median(), current.median());
+        let change = match before.is_zero() {
+            true => 0.0,
+            false => (after.as_secs_f64() / before.as_secs_f64() - 1.0) * 100.0,
+        };
+        Self {
+            event: current.event,
+            quest: current.quest,
+            part: current.part,
+            baseline: before,
+            current: after,
+            change,
+            regressed: change > threshold,
+        }
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} quest{:02} p{} {:?} -> {:?} ({:+.1}%){}",
+            event_name(self.event),
+            self.quest,
+            self.part,
+            self.baseline,
+            self.current,
+            self.change,
+            if self.regressed { " SLOWER" } else { "" }
+        )
+    }
+}
+
+/// Compare each result with its saved baseline. Parts without a baseline are skipped.
+pub fn compare(dir: &Path, results: &[Bench], threshold: f64) -> anyhow::Result<Vec<Comparison>> {
+    let mut comparisons = vec![];
+    for current in results {
+        let baselines = load(dir, current.quest)?;
+        if let Some(baseline) = baselines.iter().find(|b| b.part == current.part) {
+            comparisons.push(Comparison::new(current, baseline, threshold));
+        }
+    }
+    Ok(comparisons)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bench::Stats;
+
+    fn bench(quest: u8, part: u8, ms: u64) -> Bench {
+        let stats = Stats::new(&mut [Duration::from_millis(ms)]);
+        Bench {
+            event: 2025,
+            quest,
+            part,
+            samples: 1,
+            parse: Stats::new(&mut [Duration::ZERO]),
+            solve: stats,
+        }
+    }
+
+    #[test]
+    fn test_compare() {
+        let comparison = Comparison::new(&bench(1, 1, 115), &bench(1, 1, 100), 10.0);
+        assert!(comparison.regressed);
+        assert!((comparison.change - 15.0).abs() < 1e-9);
+        assert_eq!(
+            comparison.to_string(),
+            "2025 quest01 p1 100ms -> 115ms (+15.0%) SLOWER"
+        );
+
+        let comparison = Comparison::new(&bench(1, 1, 105), &bench(1, 1, 100), 10.0);
+        assert!(!comparison.regressed);
+        assert!(!Comparison::new(&bench(1, 1, 50), &bench(1, 1, 100), 10.0).regressed);
+    }
+
+    #[test]
+    fn test_save_and_compare() -> anyhow::Result<()> {
+        let dir = std::env::temp_dir().join(format!("ec-baseline-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        save(&dir, &[bench(3, 1, 10), bench(3, 2, 20), bench(4, 1, 30)])?;
+        // Saving part 2 again keeps the old part 1.
+        save(&dir, &[bench(3, 2, 40)])?;
+        assert_eq!(load(&dir, 3)?.len(), 2);
+        assert_eq!(load(&dir, 5)?.len(), 0);
+
+        let current = [bench(3, 1, 10), bench(3, 2, 50), bench(3, 3, 1)];
+        let comparisons = compare(&dir, &current, 20.0)?;
+        assert_eq!(comparisons.len(), 2);
+        assert!(!comparisons[0].regressed);
+        assert!(comparisons[1].regressed);
+
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,184 @@
+use std::{fmt, time::Duration};
+
+use ec_common::Entry;
+use serde_json::{Value, json};
+
+/// Summary of a set of timing samples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    pub fn new(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples");
+        samples.sort();
+
+        // Nearest rank, so the percentiles are always one of the samples.
+        let rank = |p: usize| samples[(samples.len() * p).div_ceil(100).max(1) - 1];
+        Self {
+            min: samples[0],
+            median: rank(50),
+            p95: rank(95),
+        }
+    }
+
+    fn to_json(self) -> Value {
+        json!({
+            "min_ns": self.min.as_nanos() as u64,
+            "median_ns": self.median.as_nanos() as u64,
+            "p95_ns": self.p95.as_nanos() as u64,
+        })
+    }
+
+    fn from_json(json: &Value) -> Option<Self> {
+        let ns = |name: &str| json[name].as_u64().map(Duration::from_nanos);
+        Some(Self {
+            min: ns("min_ns")?,
+            median: ns("median_ns")?,
+            p95: ns("p95_ns")?,
+        })
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}/{:?}/{:?}", self.min, self.median, self.p95)
+    }
+}
+
+/// The timings for one part of a quest.
+#[derive(Debug, Clone)]
+pub struct Bench {
+    pub event: u16,
+    pub quest: u8,
+    pub part: u8,
+    pub samples: usize,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+impl Bench {
+    /// The typical time for the whole part, parsing included.
+    pub fn median(&self) -> Duration {
+        self.parse.median + self.solve.median
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "event": self.event,
+            "quest": self.quest,
+            "part": self.part,
+            "samples": self.samples,
+            "parse": self.parse.to_json(),
+            "solve": self.solve.to_json(),
+        })
+    }
+
+    pub fn from_json(json: &Value) -> Option<Self> {
+        Some(Self {
+            event: json["event"].as_u64()?.try_into().ok()?,
+            quest: json["quest"].as_u64()?.try_into().ok()?,
+            part: json["part"].as_u64()?.try_into().ok()?,
+            samples: json["samples"].as_u64()?.try_into().ok()?,
+            parse: Stats::from_json(&json["parse"])?,
+            solve: Stats::from_json(&json["solve"])?,
+        })
+    }
+}
+
+/// Parse and solve a part `samples` times. The input is parsed again for every sample since some
+/// solutions change their input as they go.
+pub fn bench(entry: &Entry, part: u8, input: &str, samples: usize) -> anyhow::Result<Bench> {
+    let mut parse = Vec::with_capacity(samples);
+    let mut solve = Vec::with_capacity(samples);
+    for _ in 0..samples.max(1) {
+        let answer = entry.run(part, input)?;
+        parse.push(answer.parse);
+        solve.push(answer.solve);
+    }
+
+    Ok(Bench {
+        event: entry.event,
+        quest: entry.quest,
+        part,
+        samples: parse.len(),
+        parse: Stats::new(&mut parse),
+        solve: Stats::new(&mut solve),
+    })
+}
+
+pub fn to_json(results: &[Bench]) -> String {
+    let results = results.iter().map(Bench::to_json).collect::<Vec<_>>();
+    serde_json::to_string_pretty(&results).unwrap()
+}
+
+pub fn to_csv(results: &[Bench]) -> String {
+    let mut csv = String::from(
+        "event,quest,part,samples,parse_min_ns,parse_median_ns,parse_p95_ns,solve_min_ns,solve_median_ns,solve_p95_ns\n",
+    );
+    for b in results {
+        let stats = [b.parse, b.solve]
+            .iter()
+            .flat_map(|s| [s.min, s.median, s.p95])
+            .map(|d| d.as_nanos().to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        csv.push_str(&format!(
+            "{},{},{},{},{stats}\n",
+            b.event, b.quest, b.part, b.samples
+        ));
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn test_stats() {
+        let mut samples = (1..=20).rev().map(ms).collect::<Vec<_>>();
+        let stats = Stats::new(&mut samples);
+        assert_eq!(
+            (stats.min, stats.median, stats.p95),
+            (ms(1), ms(10), ms(19))
+        );
+
+        let stats = Stats::new(&mut [ms(7)]);
+        assert_eq!((stats.min, stats.median, stats.p95), (ms(7), ms(7), ms(7)));
+    }
+
+    #[test]
+    fn test_output() {
+        let stats = Stats::new(&mut [ms(1), ms(2), ms(3)]);
+        let results = [Bench {
+            event: 2025,
+            quest: 7,
+            part: 2,
+            samples: 3,
+            parse: stats,
+            solve: stats,
+        }];
+
+        let csv = to_csv(&results);
+        assert_eq!(
+            csv.lines().nth(1),
+            Some("2025,7,2,3,1000000,2000000,3000000,1000000,2000000,3000000")
+        );
+
+        let json: Value = serde_json::from_str(&to_json(&results)).unwrap();
+        assert_eq!(json[0]["quest"], 7);
+        assert_eq!(json[0]["solve"]["p95_ns"], 3_000_000);
+
+        let bench = Bench::from_json(&json[0]).unwrap();
+        assert_eq!((bench.part, bench.solve), (2, stats));
+        assert_eq!(bench.median(), ms(4));
+    }
+}
@@ -0,0 +1,205 @@
+//! `ec debug`, a terminal UI for stepping through a quest's [`Stepper`] a round at a time.
+
+use anyhow::Context;
+use ec_common::Stepper;
+use ratatui::{
+    Frame,
+    crossterm::event::{self, Event, KeyCode, KeyEventKind},
+    layout::{Constraint, Layout},
+    style::Stylize,
+    text::Line,
+    widgets::{Block, Paragraph},
+};
+
+const HELP: &str =
+    "→/space step  ← back  g<N>⏎ go to round N  home start  ↑↓/pgup/pgdn scroll  q quit";
+
+pub struct Debugger {
+    title: String,
+    stepper: Stepper,
+    /// The round being typed after pressing `g`.
+    goto: Option<String>,
+    scroll: u16,
+    message: String,
+}
+
+impl Debugger {
+    pub fn new(title: impl Into<String>, stepper: Stepper) -> Self {
+        Self {
+            title: title.into(),
+            stepper,
+            goto: None,
+            scroll: 0,
+            message: String::new(),
+        }
+    }
+
+    pub fn stepper(&self) -> &Stepper {
+        &self.stepper
+    }
+
+    /// Handle a key press. Returns false when it's time to quit.
+    pub fn key(&mut self, key: KeyCode) -> bool {
+        self.message.clear();
+
+        if let Some(goto) = &mut self.goto {
+            match key {
+                KeyCode::Char(c) if c.is_ascii_digit() => goto.push(c),
+                KeyCode::Backspace => {
+                    goto.pop();
+                }
+                KeyCode::Enter => match self.goto.take().unwrap().parse() {
+                    Ok(round) => self.seek(round),
+                    Err(_) => self.message = "type the round to go to".to_string(),
+                },
+                KeyCode::Esc => self.goto = None,
+                _ => {}
+            }
+            return true;
+        }
+
+        match key {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Right | KeyCode::Char(' ' | 'l') => {
+                let stepped = self.stepper.forward();
+                if !stepped {
+                    self.message = "the simulation has finished".to_string();
+                }
+            }
+            KeyCode::Left | KeyCode::Char('h') => {
+                let stepped = self.stepper.back();
+                if !stepped {
+                    self.message = "already at the start".to_string();
+                }
+            }
+            KeyCode::Char('g') => self.goto = Some(String::new()),
+            KeyCode::Home => self.seek(0),
+            KeyCode::Up => self.scroll = self.scroll.saturating_sub(1),
+            KeyCode::Down => self.scroll = self.scroll.saturating_add(1),
+            KeyCode::PageUp => self.scroll = self.scroll.saturating_sub(20),
+            KeyCode::PageDown => self.scroll = self.scroll.saturating_add(20),
+            _ => {}
+        }
+        true
+    }
+
+    fn seek(&mut self, round: usize) {
+        let reached = self.stepper.seek(round);
+        if reached < round {
+            self.message = format!("the simulation finished at round {reached}");
+        }
+    }
+
+    pub fn draw(&self, frame: &mut Frame) {
+        let [state, status, help] = Layout::vertical([
+            Constraint::Min(0),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+
+        let mut title = format!(" {} round {} ", self.title, self.stepper.round());
+        if self.stepper.finished() {
+            title.push_str("(finished) ");
+        }
+        frame.render_widget(
+            Paragraph::new(self.stepper.state())
+                .scroll((self.scroll, 0))
+                .block(Block::bordered().title(title.bold())),
+            state,
+        );
+
+        let status_line = match self.message.is_empty() {
+            true => Line::from(self.stepper.status()),
+            false => Line::from(self.message.as_str().yellow()),
+        };
+        frame.render_widget(status_line, status);
+
+        let help_line = match &self.goto {
+            Some(goto) => Line::from(format!("go to round: {goto}")),
+            None => Line::from(HELP.dim()),
+        };
+        frame.render_widget(help_line, help);
+    }
+}
+
+/// Take over the terminal and step through the simulation until `q` is pressed.
+pub fn run(mut debugger: Debugger) -> anyhow::Result<()> {
+    let mut terminal = ratatui::try_init()
+        .inspect_err(|_| ratatui::restore())
+        .context("couldn't take over the terminal")?;
+    let result = (|| -> anyhow::Result<()> {
+        loop {
+            terminal.draw(|frame| debugger.draw(frame))?;
+            if let Event::Key(key) = event::read()?
+                && key.kind == KeyEventKind::Press
+                && !debugger.key(key.code)
+            {
+                return Ok(());
+            }
+        }
+    })();
+    ratatui::restore();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ec_common::Simulation;
+    use ratatui::{Terminal, backend::TestBackend};
+
+    #[derive(Clone)]
+    struct Countdown(usize);
+
+    impl Simulation for Countdown {
+        fn state(&self) -> String {
+            "#".repeat(self.0)
+        }
+
+        fn step(&mut self) -> bool {
+            if self.0 == 0 {
+                return false;
+            }
+            self.0 -= 1;
+            true
+        }
+    }
+
+    fn press(debugger: &mut Debugger, keys: &str) {
+        for c in keys.chars() {
+            let key = match c {
+                '>' => KeyCode::Right,
+                '<' => KeyCode::Left,
+                '\n' => KeyCode::Enter,
+                c => KeyCode::Char(c),
+            };
+            assert!(debugger.key(key));
+        }
+    }
+
+    #[test]
+    fn test_keys() {
+        let mut debugger = Debugger::new("test", Stepper::new(Countdown(5)));
+        press(&mut debugger, ">>><");
+        assert_eq!(debugger.stepper().round(), 2);
+        press(&mut debugger, "g4\n");
+        assert_eq!(debugger.stepper().round(), 4);
+        press(&mut debugger, "g99\n");
+        assert_eq!(debugger.message, "the simulation finished at round 5");
+        assert!(!debugger.key(KeyCode::Char('q')));
+    }
+
+    #[test]
+    fn test_draw() -> anyhow::Result<()> {
+        let mut debugger = Debugger::new("test", Stepper::new(Countdown(5)));
+        press(&mut debugger, ">");
+
+        let mut terminal = Terminal::new(TestBackend::new(40, 6))?;
+        terminal.draw(|frame| debugger.draw(frame))?;
+        let screen = format!("{:?}", terminal.backend().buffer());
+        assert!(screen.contains(" test round 1 "));
+        assert!(screen.contains("####"));
+        Ok(())
+    }
+}
@@ -0,0 +1,189 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use aes::cipher::{BlockDecryptMut, KeyIvInit, block_padding::Pkcs7};
+use anyhow::{Context, anyhow};
+use ec_common::input;
+use serde_json::Value;
+
+use crate::http::Http;
+
+type Decryptor = cbc::Decryptor<aes::Aes256>;
+
+/// The keys for a quest are saved next to the inputs (e.g. `quest07-keys.json`). They hold a
+/// `keyN` for every unlocked part and an `answerN` for every solved one.
+pub fn keys_path(dir: &Path, quest: u8) -> PathBuf {
+    dir.join(format!("quest{quest:02}-keys.json"))
+}
+
+/// The encrypted input for every part (e.g. `quest07.json`).
+pub fn encrypted_path(dir: &Path, quest: u8) -> PathBuf {
+    dir.join(format!("quest{quest:02}.json"))
+}
+
+/// Decrypt a part of the input. Parts are hex encoded AES-256-CBC using the part's key, with the
+/// first 16 characters of the key as the IV.
+pub fn decrypt(key: &str, encrypted: &str) -> anyhow::Result<String> {
+    let iv = key
+        .get(..16)
+        .ok_or_else(|| anyhow!("key {key:?} is too short"))?;
+    let cipher = Decryptor::new_from_slices(key.as_bytes(), iv.as_bytes())
+        .map_err(|_| anyhow!("key {key:?} isn't a valid AES-256 key"))?;
+    let data = hex::decode(encrypted.trim()).context("encrypted input isn't hex")?;
+    let plain = cipher
+        .decrypt_padded_vec_mut::<Pkcs7>(&data)
+        .map_err(|_| anyhow!("couldn't decrypt input with key {key:?}"))?;
+    Ok(String::from_utf8(plain)?)
+}
+
+// Pull a string field out of one of the JSON responses.
+pub(crate) fn field(json: &Value, name: &str) -> anyhow::Result<String> {
+    match &json[name] {
+        Value::String(s) => Ok(s.clone()),
+        Value::Number(n) => Ok(n.to_string()),
+        _ => Err(anyhow!("{name} not found")),
+    }
+}
+
+/// Download and decrypt the input for the given part, writing it to `questNN-N.txt` in `dir`.
+/// Like `helper.nu`, part 1 is also copied to parts 2 and 3 so the quest runs before those are
+/// unlocked.
+pub fn get_input(
+    http: &impl Http,
+    dir: &Path,
+    event: u16,
+    quest: u8,
+    part: u8,
+) -> anyhow::Result<PathBuf> {
+    fs::create_dir_all(dir)?;
+
+    // Always refresh the keys since new parts unlock over time.
+    let keys = http.get(&format!("/api/event/{event}/quest/{quest}"))?;
+    fs::write(keys_path(dir, quest), &keys)?;
+
+    // The encrypted input doesn't change, so only fetch it once.
+    let encrypted_path = encrypted_path(dir, quest);
+    if fs::metadata(&encrypted_path).map_or(true, |m| m.len() == 0) {
+        let user: Value = serde_json::from_str(&http.get("/api/user/me")?)?;
+        let seed = field(&user, "seed")?;
+        let encrypted = http.get(&format!("/assets/{event}/{quest}/input/{seed}.json"))?;
+        fs::write(&encrypted_path, encrypted)?;
+    }
+
+    let keys: Value = serde_json::from_str(&keys)?;
+    let key = field(&keys, &format!("key{part}"))
+        .with_context(|| format!("part {part} of quest{quest:02} isn't unlocked yet"))?;
+    let encrypted: Value = serde_json::from_str(&fs::read_to_string(&encrypted_path)?)?;
+    let input = decrypt(&key, &field(&encrypted, &part.to_string())?)?;
+
+    let path = input::path(dir, quest, part);
+    fs::write(&path, input)?;
+    if part == 1 {
+        for copy in 2..=3 {
+            fs::copy(&path, input::path(dir, quest, copy))?;
+        }
+    }
+
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, collections::HashMap};
+
+    use aes::cipher::BlockEncryptMut;
+
+    use super::*;
+
+    const KEY1: &str = "0123456789abcdefghijklmnopqrstuv";
+    const KEY2: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ012345";
+
+    fn encrypt(key: &str, plain: &str) -> String {
+        let cipher =
+            cbc::Encryptor::<aes::Aes256>::new_from_slices(key.as_bytes(), &key.as_bytes()[..16])
+                .unwrap();
+        hex::encode(cipher.encrypt_padded_vec_mut::<Pkcs7>(plain.as_bytes()))
+    }
+
+    // Recorded responses keyed by path. Every request is logged so we can check what was fetched.
+    struct Fixtures {
+        responses: HashMap<String, String>,
+        requests: RefCell<Vec<String>>,
+    }
+
+    impl Http for Fixtures {
+        fn get(&self, path: &str) -> anyhow::Result<String> {
+            self.requests.borrow_mut().push(path.to_string());
+            self.responses
+                .get(path)
+                .cloned()
+                .ok_or_else(|| anyhow!("404 {path}"))
+        }
+
+        fn post(&self, path: &str, _: &str) -> anyhow::Result<crate::http::Response> {
+            Err(anyhow!("unexpected POST {path}"))
+        }
+    }
+
+    fn fixtures() -> Fixtures {
+        let encrypted = format!(
+            r#"{{"1":"{}","2":"{}","3":"{}"}}"#,
+            encrypt(KEY1, "part one"),
+            encrypt(KEY2, "part two"),
+            encrypt(KEY1, "part three"),
+        );
+        let responses = [
+            (
+                "/api/event/2025/quest/7",
+                format!(r#"{{"key1":"{KEY1}","key2":"{KEY2}"}}"#),
+            ),
+            (
+                "/api/user/me",
+                r#"{"seed":42,"name":"someone"}"#.to_string(),
+            ),
+            ("/assets/2025/7/input/42.json", encrypted),
+        ];
+        Fixtures {
+            responses: responses
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v))
+                .collect(),
+            requests: RefCell::default(),
+        }
+    }
+
+    #[test]
+    fn test_decrypt() {
+        assert_eq!(decrypt(KEY1, &encrypt(KEY1, "hello")).unwrap(), "hello");
+        assert!(decrypt(KEY2, &encrypt(KEY1, "hello")).is_err());
+        assert!(decrypt("short", "00").is_err());
+    }
+
+    #[test]
+    fn test_get_input() -> anyhow::Result<()> {
+        let dir = std::env::temp_dir().join(format!("ec-fetch-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let http = fixtures();
+
+        // Part 1 is copied to the other parts.
+        get_input(&http, &dir, 2025, 7, 1)?;
+        for part in 1..=3 {
+            assert_eq!(input::read(&dir, 7, part)?, "part one");
+        }
+
+        // The encrypted input is only downloaded once.
+        get_input(&http, &dir, 2025, 7, 2)?;
+        assert_eq!(input::read(&dir, 7, 2)?, "part two");
+        assert_eq!(input::read(&dir, 7, 3)?, "part one");
+        assert_eq!(http.requests.borrow().len(), 4);
+
+        // Part 3 isn't unlocked.
+        let err = get_input(&http, &dir, 2025, 7, 3).unwrap_err();
+        assert_eq!(err.to_string(), "part 3 of quest07 isn't unlocked yet");
+
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+use anyhow::Context;
+
+/// Where the site lives. Everything we fetch is relative to this so tests can point a [`Client`]
+/// at a local server instead.
+pub const BASE_URL: &str = "https://everybody.codes";
+
+/// The requests we make to everybody.codes. Paths are relative to the site (e.g.
+/// `/api/user/me`) so this can be swapped out for recorded fixtures in tests.
+pub trait Http {
+    fn get(&self, path: &str) -> anyhow::Result<String>;
+
+    /// Post a JSON `body`. Unlike [`Http::get`], error statuses are returned rather than treated
+    /// as errors since the site uses them to say why an answer wasn't accepted.
+    fn post(&self, path: &str, body: &str) -> anyhow::Result<Response>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Talks to the site using the `everybody-codes` session cookie.
+pub struct Client {
+    base: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(base: &str, session: &str) -> Self {
+        Self {
+            base: base.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+        }
+    }
+
+    /// Use the session from `EC_SESSION`, the same as `helper.nu`.
+    pub fn from_env() -> anyhow::Result<Self> {
+        let session = std::env::var("EC_SESSION")
+            .ok()
+            .filter(|s| !s.is_empty())
+            .context("EC_SESSION environment variable is not set")?;
+        Ok(Self::new(BASE_URL, &session))
+    }
+}
+
+impl Http for Client {
+    fn get(&self, path: &str) -> anyhow::Result<String> {
+        let url = format!("{}{path}", self.base);
+        ureq::get(&url)
+            .header("Cookie", format!("everybody-codes={}", self.session))
+            .call()
+            .and_then(|mut r| r.body_mut().read_to_string())
+            .with_context(|| format!("GET {url}"))
+    }
+
+    fn post(&self, path: &str, body: &str) -> anyhow::Result<Response> {
+        let url = format!("{}{path}", self.base);
+        ureq::post(&url)
+            .header("Cookie", format!("everybody-codes={}", self.session))
+            .header("Content-Type", "application/json")
+            .config()
+            .http_status_as_error(false)
+            .build()
+            .send(body)
+            .and_then(|mut r| {
+                Ok(Response {
+                    status: r.status().as_u16(),
+                    body: r.body_mut().read_to_string()?,
+                })
+            })
+            .with_context(|| format!("POST {url}"))
+    }
+}
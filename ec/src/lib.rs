@@ -0,0 +1,63 @@
+//! The `ec` runner. Every event and story registers its quests here so they can be run from a
+//! single binary.
+
+pub mod answers;
+pub mod baseline;
+pub mod bench;
+pub mod debugger;
+pub mod fetch;
+pub mod http;
+pub mod scaffold;
+pub mod submit;
+
+use std::path::{Path, PathBuf};
+
+use anyhow::bail;
+use ec_common::{Entry, solution::event_name};
+
+/// The root of the workspace, where each event and story has its own crate.
+pub fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_path_buf()
+}
+
+/// Every registered quest from every event and story.
+pub fn quests() -> Vec<Entry> {
+    [
+        ec_2024::quests(),
+        ec_2025::quests(),
+        story_1::quests(),
+        story_2::quests(),
+    ]
+    .concat()
+}
+
+/// Find the quests for the given event. If `quest` is given, only that quest is returned.
+pub fn find(event: u16, quest: Option<u8>) -> anyhow::Result<Vec<Entry>> {
+    let found = quests()
+        .into_iter()
+        .filter(|e| e.event == event && quest.is_none_or(|q| e.quest == q))
+        .collect::<Vec<_>>();
+
+    match (found.is_empty(), quest) {
+        (true, Some(quest)) => bail!("{} has no quest{quest:02}", event_name(event)),
+        (true, None) => bail!("{} has no quests", event_name(event)),
+        (false, _) => Ok(found),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find(2025, None).unwrap().len(), 20);
+        assert_eq!(find(2024, Some(7)).unwrap()[0].quest, 7);
+        assert_eq!(find(1, Some(3)).unwrap()[0].event, 1);
+        assert!(find(2025, Some(99)).is_err());
+        assert!(find(2023, None).is_err());
+    }
+}
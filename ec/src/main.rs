@@ -0,0 +1,399 @@
+use std::{fs, ops::Range, path::PathBuf, process::ExitCode, time::Duration};
+
+use anyhow::{Context, bail};
+use clap::{Parser, Subcommand, ValueEnum};
+use ec::{
+    answers::{Answers, Verdict},
+    baseline, bench,
+    debugger::{self, Debugger},
+    fetch,
+    http::Client,
+    scaffold,
+    submit::{self, Outcome},
+};
+use ec_common::{Recorder, animation, export, input, solution::event_name};
+
+#[derive(Parser)]
+#[command(about = "Solutions to https://everybody.codes")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solutions for an event or story.
+    Run {
+        /// The event year (2024, 2025, ...) or story number (1, 2, ...).
+        event: u16,
+
+        /// Only run this quest.
+        quest: Option<u8>,
+
+        /// Only run this part.
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=3))]
+        part: Option<u8>,
+
+        /// Read inputs from this directory instead of the event's `src/inputs`.
+        #[arg(short, long, env = "EC_INPUTS")]
+        inputs: Option<PathBuf>,
+
+        /// Draw the part to this PNG or SVG file, for quests that know how.
+        #[arg(long, requires_all = ["quest", "part"])]
+        render: Option<PathBuf>,
+
+        /// Record the part's simulation to this GIF, or to numbered PNGs in this directory.
+        #[arg(long, requires_all = ["quest", "part"])]
+        animate: Option<PathBuf>,
+
+        /// Write what the part worked out to this DOT or JSON file, for quests that know how.
+        #[arg(long, requires_all = ["quest", "part"])]
+        export: Option<PathBuf>,
+
+        /// Fail instead of warning when a part had to guess at its answer.
+        #[arg(long)]
+        strict: bool,
+
+        /// The rounds to record with `--animate` (e.g. `100..200` or `100..=199`).
+        #[arg(long, default_value = "0..100", value_parser = animation::parse_frames)]
+        frames: Range<usize>,
+
+        /// How long each frame of a GIF is shown, in milliseconds.
+        #[arg(long, default_value_t = 100)]
+        delay: u64,
+    },
+
+    /// Step through a part's simulation in the terminal, for quests that have one.
+    Debug {
+        /// The event year (2024, 2025, ...) or story number (1, 2, ...).
+        event: u16,
+
+        quest: u8,
+
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=3))]
+        part: u8,
+
+        /// Start at this round.
+        #[arg(short, long, default_value_t = 0)]
+        round: usize,
+
+        /// Read inputs from this directory instead of the event's `src/inputs`.
+        #[arg(short, long, env = "EC_INPUTS")]
+        inputs: Option<PathBuf>,
+    },
+
+    /// Time the solutions for an event or story over many runs.
+    Bench {
+        /// The event year (2024, 2025, ...) or story number (1, 2, ...).
+        event: u16,
+
+        /// Only bench this quest.
+        quest: Option<u8>,
+
+        /// Only bench this part.
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=3))]
+        part: Option<u8>,
+
+        /// How many times to parse and solve each part.
+        #[arg(short, long, default_value_t = 20)]
+        samples: usize,
+
+        #[arg(short, long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+
+        /// Write the results here instead of stdout.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Save the results as the new baselines.
+        #[arg(long)]
+        save: bool,
+
+        /// Compare the results with the saved baselines and fail if any part got slower.
+        #[arg(long)]
+        compare: bool,
+
+        /// How much slower (in percent) a part can get before `--compare` fails.
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+
+        /// Keep baselines in this directory instead of the event's `baselines`.
+        #[arg(long)]
+        baselines: Option<PathBuf>,
+
+        /// Read inputs from this directory instead of the event's `src/inputs`.
+        #[arg(short, long, env = "EC_INPUTS")]
+        inputs: Option<PathBuf>,
+    },
+
+    /// Download and decrypt the input for a part of a quest. Needs `EC_SESSION` to be set.
+    Fetch {
+        /// The event year (2024, 2025, ...) or story number (1, 2, ...).
+        event: u16,
+
+        quest: u8,
+
+        #[arg(default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=3))]
+        part: u8,
+
+        /// Write inputs to this directory instead of the event's `src/inputs`.
+        #[arg(short, long, env = "EC_INPUTS")]
+        inputs: Option<PathBuf>,
+    },
+
+    /// Start a new quest from `template.rs` and register it with the runner.
+    New {
+        /// The crate to add it to (e.g. `ec_2025` or `story_1`).
+        workspace: String,
+
+        quest: u8,
+
+        /// Use the `questNN-P.txt` files in this directory as the example inputs in the tests.
+        #[arg(short, long)]
+        examples: Option<PathBuf>,
+    },
+
+    /// Submit the answer for a part of a quest. Needs `EC_SESSION` to be set.
+    Submit {
+        /// The event year (2024, 2025, ...) or story number (1, 2, ...).
+        event: u16,
+
+        quest: u8,
+
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=3))]
+        part: u8,
+
+        answer: String,
+
+        /// Save accepted answers to the keys in this directory instead of the event's
+        /// `src/inputs`.
+        #[arg(short, long, env = "EC_INPUTS")]
+        inputs: Option<PathBuf>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+fn main() -> ExitCode {
+    // Print the whole chain on one line (e.g. `quest12 part 2: bad digit 'x' at line 3 col 7`).
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {e:#}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cli: Cli) -> anyhow::Result<()> {
+    match cli.command {
+        Command::Run {
+            event,
+            quest,
+            part,
+            inputs,
+            render,
+            animate,
+            export,
+            strict,
+            frames,
+            delay,
+        } => {
+            let entries = ec::find(event, quest)?;
+            let inputs = inputs.unwrap_or_else(|| input::default_dir(event));
+            let parts = part.map_or(1..=3, |p| p..=p);
+            let mut failed = 0;
+            for entry in &entries {
+                // Only label the output when we're running more than one quest.
+                if entries.len() > 1 {
+                    println!("{} quest{:02}", event_name(entry.event), entry.quest);
+                }
+
+                let answers = Answers::load(&inputs, entry.quest)?;
+                for part in parts.clone() {
+                    let input = input::read(&inputs, entry.quest, part)?;
+                    let answer = entry.run(part, &input)?;
+                    let guesses = entry.guesses(part, &input)?;
+                    if !guesses.is_empty() {
+                        let message = format!(
+                            "quest{:02} part {part} had to guess:\n{}",
+                            entry.quest,
+                            guesses.join("\n")
+                        );
+                        match strict {
+                            true => bail!(message),
+                            false => eprintln!("{message}"),
+                        }
+                    }
+                    let verdict = answers.check(part, &answer.value);
+                    println!("p{part} {:?} {} {verdict}", answer.elapsed(), answer.value);
+                    failed += matches!(verdict, Verdict::Fail(_)) as usize;
+
+                    if let Some(path) = &render {
+                        match entry.render(part, &input)? {
+                            Some(picture) => picture.save(path)?,
+                            None => bail!(
+                                "quest{:02} part {part} doesn't have anything to render",
+                                entry.quest
+                            ),
+                        }
+                    }
+
+                    if let Some(path) = &animate {
+                        let mut recorder = Recorder::new(frames.clone());
+                        entry.animate(part, &input, &mut recorder)?;
+                        if recorder.is_empty() {
+                            bail!(
+                                "quest{:02} part {part} doesn't have anything to animate in rounds {frames:?}",
+                                entry.quest
+                            );
+                        }
+                        for note in recorder.notes() {
+                            eprintln!("{note}");
+                        }
+                        recorder.save(path, Duration::from_millis(delay))?;
+                        eprintln!("recorded {} frames", recorder.len());
+                    }
+
+                    if let Some(path) = &export {
+                        let format = export::Format::from_path(path)?;
+                        match entry.export(part, &input, format)? {
+                            Some(contents) => fs::write(path, contents)
+                                .with_context(|| format!("couldn't write {}", path.display()))?,
+                            None => bail!(
+                                "quest{:02} part {part} doesn't have anything to export as {format:?}",
+                                entry.quest
+                            ),
+                        }
+                    }
+                }
+            }
+
+            if failed > 0 {
+                bail!("{failed} part(s) didn't match the accepted answer");
+            }
+        }
+        Command::Debug {
+            event,
+            quest,
+            part,
+            round,
+            inputs,
+        } => {
+            let entry = ec::find(event, Some(quest))?[0];
+            let inputs = inputs.unwrap_or_else(|| input::default_dir(event));
+            let input = input::read(&inputs, quest, part)?;
+            let Some(mut stepper) = entry.simulate(part, &input)? else {
+                bail!("quest{quest:02} part {part} doesn't have a simulation to step through");
+            };
+            stepper.seek(round);
+            let title = format!("{} quest{quest:02} part {part}", event_name(event));
+            debugger::run(Debugger::new(title, stepper))?;
+        }
+        Command::Bench {
+            event,
+            quest,
+            part,
+            samples,
+            format,
+            output,
+            save,
+            compare,
+            threshold,
+            baselines,
+            inputs,
+        } => {
+            let entries = ec::find(event, quest)?;
+            let inputs = inputs.unwrap_or_else(|| input::default_dir(event));
+            let parts = part.map_or(1..=3, |p| p..=p);
+            let mut results = vec![];
+            for entry in &entries {
+                for part in parts.clone() {
+                    let input = input::read(&inputs, entry.quest, part)?;
+                    results.push(bench::bench(entry, part, &input, samples)?);
+                }
+            }
+
+            let report = match format {
+                Format::Text => results
+                    .iter()
+                    .map(|b| {
+                        format!(
+                            "{} quest{:02} p{} parse {} solve {}\n",
+                            event_name(b.event),
+                            b.quest,
+                            b.part,
+                            b.parse,
+                            b.solve
+                        )
+                    })
+                    .collect(),
+                Format::Json => bench::to_json(&results),
+                Format::Csv => bench::to_csv(&results),
+            };
+            match output {
+                Some(path) => fs::write(path, report)?,
+                None => print!("{report}"),
+            }
+
+            let baselines = baselines.unwrap_or_else(|| baseline::default_dir(event));
+            if compare {
+                // These go to stderr so they don't end up mixed in with JSON or CSV.
+                let comparisons = baseline::compare(&baselines, &results, threshold)?;
+                for comparison in &comparisons {
+                    eprintln!("{comparison}");
+                }
+                let slower = comparisons.iter().filter(|c| c.regressed).count();
+                if slower > 0 {
+                    bail!("{slower} part(s) got more than {threshold}% slower");
+                }
+            }
+            if save {
+                baseline::save(&baselines, &results)?;
+            }
+        }
+        Command::Fetch {
+            event,
+            quest,
+            part,
+            inputs,
+        } => {
+            let inputs = inputs.unwrap_or_else(|| input::default_dir(event));
+            let path = fetch::get_input(&Client::from_env()?, &inputs, event, quest, part)?;
+            println!("{}", path.display());
+        }
+        Command::New {
+            workspace,
+            quest,
+            examples,
+        } => {
+            let root = ec::workspace_root();
+            let path = scaffold::create(&root, &workspace, quest, examples.as_deref())?;
+            println!("{}", path.display());
+        }
+        Command::Submit {
+            event,
+            quest,
+            part,
+            answer,
+            inputs,
+        } => {
+            let inputs = inputs.unwrap_or_else(|| input::default_dir(event));
+            let client = Client::from_env()?;
+            match submit::submit(&client, &inputs, event, quest, part, &answer)? {
+                Outcome::Correct => println!("correct"),
+                Outcome::Incorrect { length, first } => {
+                    bail!("incorrect (length correct: {length}, first character correct: {first})")
+                }
+                Outcome::Locked(message) => bail!("locked: {message}"),
+            }
+        }
+    }
+
+    Ok(())
+}
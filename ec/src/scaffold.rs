@@ -0,0 +1,162 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, bail};
+use ec_common::input;
+
+const TEMPLATE: &str = include_str!("../../template.rs");
+
+// The template's tests all start from this input. It's swapped for the example when we have one.
+const PLACEHOLDER: &str = r#""123\n456\n789\n""#;
+
+/// Fill in the template for the given quest. `examples` replace the test input for each part.
+pub fn render(quest: u8, examples: &[Option<String>; 3]) -> String {
+    let mut source = TEMPLATE.replace("[QUEST]", &quest.to_string());
+
+    // Replace the placeholders one at a time since each test is for a different part.
+    let mut from = 0;
+    for example in examples {
+        let Some(start) = source[from..].find(PLACEHOLDER).map(|i| i + from) else {
+            break;
+        };
+        let replacement = match example {
+            Some(example) => format!("{example:?}"),
+            None => PLACEHOLDER.to_string(),
+        };
+        source.replace_range(start..start + PLACEHOLDER.len(), &replacement);
+        from = start + replacement.len();
+    }
+    source
+}
+
+// Insert `line` for `quest` among the other `questNN` lines that look like it, keeping them sorted.
+fn insert(lines: &mut Vec<String>, quest: u8, prefix: &str, suffix: &str) -> anyhow::Result<()> {
+    let number = |line: &str| {
+        let name = line.trim().strip_prefix(prefix)?.strip_suffix(suffix)?;
+        name.strip_prefix("quest")?.parse::<u8>().ok()
+    };
+
+    let existing = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| Some((i, number(l)?)))
+        .collect::<Vec<_>>();
+    if existing.iter().any(|(_, q)| *q == quest) {
+        bail!("quest{quest:02} is already registered");
+    }
+
+    let (at, indent) = match existing.iter().rev().find(|(_, q)| *q < quest) {
+        Some((i, _)) => (i + 1, &lines[*i]),
+        None => match existing.first() {
+            Some((i, _)) => (*i, &lines[*i]),
+            None => bail!("couldn't find where to add {prefix}quest{quest:02}{suffix}"),
+        },
+    };
+    let indent = &indent[..indent.len() - indent.trim_start().len()];
+    let line = format!("{indent}{prefix}quest{quest:02}{suffix}");
+    lines.insert(at, line);
+    Ok(())
+}
+
+/// Add the quest's `mod` and `ENTRY` to a crate's lib.rs.
+pub fn register(lib: &str, quest: u8) -> anyhow::Result<String> {
+    let mut lines = lib.lines().map(String::from).collect::<Vec<_>>();
+    insert(&mut lines, quest, "mod ", ";")?;
+    insert(&mut lines, quest, "", "::ENTRY,")?;
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Create `src/questNN.rs` in the `workspace` crate (e.g. `ec_2025` or `story_1`) from the
+/// template and register it. If `examples` is given, any `questNN-P.txt` files in it are used as
+/// the test inputs.
+pub fn create(
+    root: &Path,
+    workspace: &str,
+    quest: u8,
+    examples: Option<&Path>,
+) -> anyhow::Result<PathBuf> {
+    let src = root.join(workspace).join("src");
+    let lib_path = src.join("lib.rs");
+    let lib = fs::read_to_string(&lib_path)
+        .with_context(|| format!("{workspace} doesn't look like one of our crates"))?;
+
+    let path = src.join(format!("quest{quest:02}.rs"));
+    if path.exists() {
+        bail!("{} already exists", path.display());
+    }
+
+    let examples = [1, 2, 3].map(|part| {
+        let path = input::path(examples?, quest, part);
+        fs::read_to_string(path).ok()
+    });
+
+    let lib = register(&lib, quest)?;
+    fs::write(&path, render(quest, &examples))?;
+    fs::write(&lib_path, lib)?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB: &str = "use ec_common::Entry;
+
+mod quest01;
+mod quest03;
+
+pub fn quests() -> Vec<Entry> {
+    vec![
+        quest01::ENTRY,
+        quest03::ENTRY,
+    ]
+}
+";
+
+    #[test]
+    fn test_render() {
+        let source = render(7, &[Some("A,B\nC".to_string()), None, None]);
+        assert!(source.contains("Entry::new::<Quest>(crate::EVENT, 7)"));
+        assert!(source.contains(r#"parse("A,B\nC")"#));
+        assert_eq!(source.matches(PLACEHOLDER).count(), 2);
+    }
+
+    #[test]
+    fn test_register() -> anyhow::Result<()> {
+        let lib = register(LIB, 2)?;
+        assert!(lib.contains("mod quest01;\nmod quest02;\nmod quest03;\n"));
+        assert!(lib.contains("        quest02::ENTRY,\n        quest03::ENTRY,\n"));
+
+        let lib = register(&lib, 4)?;
+        assert!(lib.contains("mod quest03;\nmod quest04;\n\n"));
+        assert!(lib.contains("        quest04::ENTRY,\n    ]"));
+
+        assert!(register(&lib, 3).is_err());
+        assert!(register("", 3).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_create() -> anyhow::Result<()> {
+        let root = std::env::temp_dir().join(format!("ec-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("ec_2025/src"))?;
+        fs::create_dir_all(root.join("examples"))?;
+        fs::write(root.join("ec_2025/src/lib.rs"), LIB)?;
+        fs::write(root.join("examples/quest02-2.txt"), "xyz\n")?;
+
+        let path = create(&root, "ec_2025", 2, Some(&root.join("examples")))?;
+        assert!(fs::read_to_string(path)?.contains(r#"parse("xyz\n")"#));
+        assert!(fs::read_to_string(root.join("ec_2025/src/lib.rs"))?.contains("mod quest02;"));
+
+        // We never overwrite a solution.
+        let err = create(&root, "ec_2025", 2, None).unwrap_err();
+        assert!(err.to_string().ends_with("quest02.rs already exists"));
+        assert!(create(&root, "ec_2023", 1, None).is_err());
+
+        fs::remove_dir_all(&root)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,199 @@
+use std::{fs, io, path::Path};
+
+use anyhow::{Context, bail};
+use serde_json::{Value, json};
+
+use crate::{fetch::keys_path, http::Http};
+
+/// What the site said about a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    /// The site also tells us whether the length and the first character were right.
+    Incorrect {
+        length: bool,
+        first: bool,
+    },
+    /// The part can't take answers right now (not unlocked yet, already solved or we're waiting
+    /// out the penalty for a wrong answer). Holds whatever message the site gave.
+    Locked(String),
+}
+
+impl Outcome {
+    /// Turn the site's response into an [`Outcome`].
+    pub fn parse(status: u16, body: &str) -> anyhow::Result<Self> {
+        match status {
+            200..=299 => {
+                let json: Value = serde_json::from_str(body)
+                    .with_context(|| format!("unexpected response {body:?}"))?;
+                let flag = |name: &str| json[name].as_bool();
+                match flag("correct") {
+                    Some(true) => Ok(Self::Correct),
+                    Some(false) => Ok(Self::Incorrect {
+                        length: flag("lengthCorrect").unwrap_or(false),
+                        first: flag("firstCorrect").unwrap_or(false),
+                    }),
+                    None => bail!("unexpected response {body:?}"),
+                }
+            }
+            401 | 403 => bail!("not logged in; is EC_SESSION still valid?"),
+            400..=499 => Ok(Self::Locked(body.trim().to_string())),
+            _ => bail!("server error {status}: {body}"),
+        }
+    }
+}
+
+/// Submit the answer for a part. Accepted answers are saved as `answerN` in the keys file so
+/// `ec run` can check against them.
+pub fn submit(
+    http: &impl Http,
+    dir: &Path,
+    event: u16,
+    quest: u8,
+    part: u8,
+    answer: &str,
+) -> anyhow::Result<Outcome> {
+    let path = format!("/api/event/{event}/quest/{quest}/part/{part}/answer");
+    let response = http.post(&path, &json!({ "answer": answer }).to_string())?;
+    let outcome = Outcome::parse(response.status, &response.body)?;
+    if outcome == Outcome::Correct {
+        save_answer(dir, quest, part, answer)?;
+    }
+    Ok(outcome)
+}
+
+/// Record the answer for a part in the keys file, creating it if we haven't fetched it yet.
+pub fn save_answer(dir: &Path, quest: u8, part: u8, answer: &str) -> anyhow::Result<()> {
+    let path = keys_path(dir, quest);
+    let mut keys: Value = match fs::read_to_string(&path) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => json!({}),
+        r => serde_json::from_str(&r?)
+            .with_context(|| format!("couldn't parse {}", path.display()))?,
+    };
+    keys[format!("answer{part}")] = json!(answer);
+
+    fs::create_dir_all(dir)?;
+    fs::write(&path, keys.to_string())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    use super::*;
+    use crate::{answers::Answers, http::Client};
+
+    // A local server that replays canned `(status, body)` responses, one per connection, and
+    // hands back the requests it got once they've all been used.
+    fn mock_server(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let mut requests = vec![];
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+
+                // Request line and headers, then however much body they said there was.
+                let mut request = String::new();
+                let mut len = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line == "\r\n" {
+                        break;
+                    }
+                    if let Some((name, value)) = line.split_once(':')
+                        && name.eq_ignore_ascii_case("content-length")
+                    {
+                        len = value.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                }
+                let mut buf = vec![0; len];
+                reader.read_exact(&mut buf).unwrap();
+                request.push_str(&String::from_utf8(buf).unwrap());
+                requests.push(request);
+
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 {status} OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+            requests
+        });
+        (base, handle)
+    }
+
+    #[test]
+    fn test_submit() -> anyhow::Result<()> {
+        let dir = std::env::temp_dir().join(format!("ec-submit-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let (base, server) = mock_server(vec![
+            (
+                200,
+                r#"{"correct":false,"lengthCorrect":true,"firstCorrect":false}"#,
+            ),
+            (423, "Part is locked"),
+            (
+                200,
+                r#"{"correct":true,"lengthCorrect":true,"firstCorrect":true}"#,
+            ),
+        ]);
+        let client = Client::new(&base, "secret");
+
+        let outcome = submit(&client, &dir, 2025, 3, 2, "41")?;
+        assert_eq!(
+            outcome,
+            Outcome::Incorrect {
+                length: true,
+                first: false
+            }
+        );
+        let outcome = submit(&client, &dir, 2025, 3, 2, "42")?;
+        assert_eq!(outcome, Outcome::Locked("Part is locked".to_string()));
+        assert_eq!(Answers::load(&dir, 3)?.get(2), None);
+
+        // Only the accepted answer is saved.
+        assert_eq!(submit(&client, &dir, 2025, 3, 2, "42")?, Outcome::Correct);
+        assert_eq!(Answers::load(&dir, 3)?.get(2), Some("42"));
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /api/event/2025/quest/3/part/2/answer "));
+        assert!(requests[0].contains("everybody-codes=secret"));
+        assert!(requests[0].ends_with(r#"{"answer":"41"}"#));
+
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse() {
+        assert!(Outcome::parse(401, "").is_err());
+        assert!(Outcome::parse(500, "").is_err());
+        assert!(Outcome::parse(200, "{}").is_err());
+    }
+
+    #[test]
+    fn test_save_answer() -> anyhow::Result<()> {
+        let dir = std::env::temp_dir().join(format!("ec-save-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir)?;
+        fs::write(keys_path(&dir, 1), r#"{"key1":"abc","answer1":"7"}"#)?;
+
+        save_answer(&dir, 1, 2, "8")?;
+        let answers = Answers::load(&dir, 1)?;
+        assert_eq!((answers.get(1), answers.get(2)), (Some("7"), Some("8")));
+        assert!(fs::read_to_string(keys_path(&dir, 1))?.contains(r#""key1":"abc""#));
+
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+//! Solutions for the [2024 event](https://everybody.codes/event/2024/quests).
+
+use ec_common::Entry;
+
+mod quest01;
+mod quest02;
+mod quest03;
+mod quest04;
+mod quest05;
+mod quest06;
+mod quest07;
+mod quest08;
+
+pub const EVENT: u16 = 2024;
+
+/// All of the quests solved so far.
+pub fn quests() -> Vec<Entry> {
+    vec![
+        quest01::ENTRY,
+        quest02::ENTRY,
+        quest03::ENTRY,
+        quest04::ENTRY,
+        quest05::ENTRY,
+        quest06::ENTRY,
+        quest07::ENTRY,
+        quest08::ENTRY,
+    ]
+}
@@ -1,8 +1,9 @@
-use std::time::Instant;
+use std::fmt::Display;
+
+use ec_common::{Entry, Solution};
 
 use itertools::Itertools;
 
-const INPUT1: &str = include_str!("inputs/quest01-1.txt");
 type Input1<'a> = &'a str;
 fn parse_input1(input: &'_ str) -> Input1<'_> {
     input.trim()
@@ -22,7 +23,6 @@ fn p1(input: &Input1) -> usize {
     input.chars().map(enemy_cost).sum()
 }
 
-const INPUT2: &str = include_str!("inputs/quest01-2.txt");
 type Input2<'a> = &'a str;
 fn parse_input2(input: &'_ str) -> Input2<'_> {
     input.trim()
@@ -37,7 +37,6 @@ fn p2(input: &Input2) -> usize {
     input.chars().tuples().map(p2_cost).sum()
 }
 
-const INPUT3: &str = include_str!("inputs/quest01-3.txt");
 type Input3<'a> = &'a str;
 fn parse_input3(input: &'_ str) -> Input3<'_> {
     input.trim()
@@ -59,23 +58,40 @@ fn p3(input: &Input3) -> usize {
     input.chars().tuples::<(_, _, _)>().map(p3_cost).sum()
 }
 
-fn main() {
-    let now = Instant::now();
-    let input = parse_input1(INPUT1);
-    let solution = p1(&input);
-    println!("p1 {:?} {}", now.elapsed(), solution);
-
-    let now = Instant::now();
-    let input = parse_input2(INPUT2);
-    let solution = p2(&input);
-    println!("p2 {:?} {}", now.elapsed(), solution);
-
-    let now = Instant::now();
-    let input = parse_input3(INPUT3);
-    let solution = p3(&input);
-    println!("p3 {:?} {}", now.elapsed(), solution);
+struct Quest;
+
+impl Solution for Quest {
+    type Part1<'a> = Input1<'a>;
+    type Part2<'a> = Input2<'a>;
+    type Part3<'a> = Input3<'a>;
+
+    fn parse_part1(input: &str) -> anyhow::Result<Self::Part1<'_>> {
+        Ok(parse_input1(input))
+    }
+
+    fn parse_part2(input: &str) -> anyhow::Result<Self::Part2<'_>> {
+        Ok(parse_input2(input))
+    }
+
+    fn parse_part3(input: &str) -> anyhow::Result<Self::Part3<'_>> {
+        Ok(parse_input3(input))
+    }
+
+    fn p1(input: &mut Self::Part1<'_>) -> impl Display {
+        p1(input)
+    }
+
+    fn p2(input: &mut Self::Part2<'_>) -> impl Display {
+        p2(input)
+    }
+
+    fn p3(input: &mut Self::Part3<'_>) -> impl Display {
+        p3(input)
+    }
 }
 
+pub(crate) const ENTRY: Entry = Entry::new::<Quest>(crate::EVENT, 1);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,15 +1,16 @@
-use std::time::Instant;
+use std::fmt::Display;
+
+use ec_common::{Entry, Solution, parse};
 
 use rustc_hash::FxHashSet;
 
-const INPUT_PART1: &str = include_str!("inputs/quest02-1.txt");
 type InputPart1<'a> = (Vec<&'a str>, Vec<&'a str>);
-fn parse_input_part1(input: &'_ str) -> InputPart1<'_> {
-    let (runes, inscription) = input.split_once("\n\n").unwrap();
-    (
+fn parse_input_part1(input: &'_ str) -> parse::Result<InputPart1<'_>> {
+    let (runes, inscription) = parse::split_once(input, input, "\n\n")?;
+    Ok((
         runes.trim_start_matches("WORDS:").split(',').collect(),
         inscription.split_whitespace().collect(),
-    )
+    ))
 }
 
 fn p1((runes, inscription): &InputPart1) -> usize {
@@ -27,14 +28,13 @@ fn p1((runes, inscription): &InputPart1) -> usize {
         .sum()
 }
 
-const INPUT_PART2: &str = include_str!("inputs/quest02-2.txt");
 type InputPart2<'a> = (Vec<&'a str>, &'a str);
-fn parse_input_part2(input: &'_ str) -> InputPart2<'_> {
-    let (runes, inscription) = input.split_once("\n\n").unwrap();
-    (
+fn parse_input_part2(input: &'_ str) -> parse::Result<InputPart2<'_>> {
+    let (runes, inscription) = parse::split_once(input, input, "\n\n")?;
+    Ok((
         runes.trim_start_matches("WORDS:").split(',').collect(),
         inscription.trim(),
-    )
+    ))
 }
 
 fn p2((runes, inscription): &InputPart2) -> usize {
@@ -60,18 +60,17 @@ fn p2((runes, inscription): &InputPart2) -> usize {
     seen.len()
 }
 
-const INPUT_PART3: &str = include_str!("inputs/quest02-3.txt");
 type InputPart3<'a> = (Vec<Vec<char>>, Vec<Vec<char>>);
-fn parse_input_part3(input: &'_ str) -> InputPart3<'_> {
-    let (runes, inscription) = input.split_once("\n\n").unwrap();
-    (
+fn parse_input_part3(input: &'_ str) -> parse::Result<InputPart3<'_>> {
+    let (runes, inscription) = parse::split_once(input, input, "\n\n")?;
+    Ok((
         runes
             .trim_start_matches("WORDS:")
             .split(',')
             .map(|w| w.chars().collect())
             .collect(),
         inscription.lines().map(|l| l.chars().collect()).collect(),
-    )
+    ))
 }
 
 enum Direction {
@@ -146,23 +145,40 @@ fn p3((runes, inscription): &InputPart3) -> usize {
     seen.len()
 }
 
-fn main() {
-    let now = Instant::now();
-    let input = parse_input_part1(INPUT_PART1);
-    let solution = p1(&input);
-    println!("p1 {:?} {}", now.elapsed(), solution);
-
-    let now = Instant::now();
-    let input = parse_input_part2(INPUT_PART2);
-    let solution = p2(&input);
-    println!("p2 {:?} {}", now.elapsed(), solution);
-
-    let now = Instant::now();
-    let input = parse_input_part3(INPUT_PART3);
-    let solution = p3(&input);
-    println!("p3 {:?} {}", now.elapsed(), solution);
+struct Quest;
+
+impl Solution for Quest {
+    type Part1<'a> = InputPart1<'a>;
+    type Part2<'a> = InputPart2<'a>;
+    type Part3<'a> = InputPart3<'a>;
+
+    fn parse_part1(input: &str) -> anyhow::Result<Self::Part1<'_>> {
+        Ok(parse_input_part1(input)?)
+    }
+
+    fn parse_part2(input: &str) -> anyhow::Result<Self::Part2<'_>> {
+        Ok(parse_input_part2(input)?)
+    }
+
+    fn parse_part3(input: &str) -> anyhow::Result<Self::Part3<'_>> {
+        Ok(parse_input_part3(input)?)
+    }
+
+    fn p1(input: &mut Self::Part1<'_>) -> impl Display {
+        p1(input)
+    }
+
+    fn p2(input: &mut Self::Part2<'_>) -> impl Display {
+        p2(input)
+    }
+
+    fn p3(input: &mut Self::Part3<'_>) -> impl Display {
+        p3(input)
+    }
 }
 
+pub(crate) const ENTRY: Entry = Entry::new::<Quest>(crate::EVENT, 2);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,15 +1,17 @@
-use std::time::Instant;
+use std::fmt::Display;
+
+use ec_common::{Entry, ParseError, Solution, parse};
 
-const INPUT_PART1: &str = include_str!("inputs/quest03-1.txt");
 type InputPart1<'a> = Vec<Vec<usize>>;
-fn parse_input_part1(input: &'_ str) -> InputPart1<'_> {
+fn parse_input_part1(input: &'_ str) -> parse::Result<InputPart1<'_>> {
     input
         .lines()
         .map(|l| {
-            l.chars()
+            parse::chars(l)
                 .map(|c| match c {
-                    '.' => 0,
-                    _ => 1, // #
+                    "." => Ok(0),
+                    "#" => Ok(1),
+                    _ => Err(ParseError::at(input, c, format!("bad character '{c}'"))),
                 })
                 .collect()
         })
@@ -46,16 +48,16 @@ fn p1(input: &InputPart1) -> usize {
     input.iter().map(|r| r.iter().sum::<usize>()).sum()
 }
 
-const INPUT_PART2: &str = include_str!("inputs/quest03-2.txt");
 type InputPart2<'a> = Vec<Vec<usize>>;
-fn parse_input_part2(input: &'_ str) -> InputPart2<'_> {
+fn parse_input_part2(input: &'_ str) -> parse::Result<InputPart2<'_>> {
     input
         .lines()
         .map(|l| {
-            l.chars()
+            parse::chars(l)
                 .map(|c| match c {
-                    '.' => 0,
-                    _ => 1, // #
+                    "." => Ok(0),
+                    "#" => Ok(1),
+                    _ => Err(ParseError::at(input, c, format!("bad character '{c}'"))),
                 })
                 .collect()
         })
@@ -91,16 +93,16 @@ fn p2(input: &InputPart2) -> usize {
     input.iter().map(|r| r.iter().sum::<usize>()).sum()
 }
 
-const INPUT_PART3: &str = include_str!("inputs/quest03-3.txt");
 type InputPart3<'a> = Vec<Vec<usize>>;
-fn parse_input_part3(input: &'_ str) -> InputPart3<'_> {
+fn parse_input_part3(input: &'_ str) -> parse::Result<InputPart3<'_>> {
     input
         .lines()
         .map(|l| {
-            l.chars()
+            parse::chars(l)
                 .map(|c| match c {
-                    '.' => 0,
-                    _ => 1, // #
+                    "." => Ok(0),
+                    "#" => Ok(1),
+                    _ => Err(ParseError::at(input, c, format!("bad character '{c}'"))),
                 })
                 .collect()
         })
@@ -151,23 +153,40 @@ fn p3(input: &InputPart3) -> usize {
     input.iter().map(|r| r.iter().sum::<usize>()).sum()
 }
 
-fn main() {
-    let now = Instant::now();
-    let input = parse_input_part1(INPUT_PART1);
-    let solution = p1(&input);
-    println!("p1 {:?} {}", now.elapsed(), solution);
-
-    let now = Instant::now();
-    let input = parse_input_part2(INPUT_PART2);
-    let solution = p2(&input);
-    println!("p2 {:?} {}", now.elapsed(), solution);
-
-    let now = Instant::now();
-    let input = parse_input_part3(INPUT_PART3);
-    let solution = p3(&input);
-    println!("p3 {:?} {}", now.elapsed(), solution);
+struct Quest;
+
+impl Solution for Quest {
+    type Part1<'a> = InputPart1<'a>;
+    type Part2<'a> = InputPart2<'a>;
+    type Part3<'a> = InputPart3<'a>;
+
+    fn parse_part1(input: &str) -> anyhow::Result<Self::Part1<'_>> {
+        Ok(parse_input_part1(input)?)
+    }
+
+    fn parse_part2(input: &str) -> anyhow::Result<Self::Part2<'_>> {
+        Ok(parse_input_part2(input)?)
+    }
+
+    fn parse_part3(input: &str) -> anyhow::Result<Self::Part3<'_>> {
+        Ok(parse_input_part3(input)?)
+    }
+
+    fn p1(input: &mut Self::Part1<'_>) -> impl Display {
+        p1(input)
+    }
+
+    fn p2(input: &mut Self::Part2<'_>) -> impl Display {
+        p2(input)
+    }
+
+    fn p3(input: &mut Self::Part3<'_>) -> impl Display {
+        p3(input)
+    }
 }
 
+pub(crate) const ENTRY: Entry = Entry::new::<Quest>(crate::EVENT, 3);
+
 // ..........
 // ..###.##..
 // ...####...
@@ -0,0 +1,69 @@
+use std::fmt::Display;
+
+use ec_common::{Entry, Solution, parse};
+
+type InputPart1<'a> = Vec<usize>;
+fn parse_input_part1(input: &'_ str) -> parse::Result<InputPart1<'_>> {
+    input.lines().map(|l| parse::number(input, l)).collect()
+}
+
+fn p1(input: &InputPart1) -> usize {
+    let min = input.iter().min().unwrap();
+    input.iter().map(|i| *i - min).sum()
+}
+
+type InputPart2<'a> = Vec<usize>;
+fn parse_input_part2(input: &'_ str) -> parse::Result<InputPart2<'_>> {
+    input.lines().map(|l| parse::number(input, l)).collect()
+}
+
+fn p2(input: &InputPart2) -> usize {
+    let min = input.iter().min().unwrap();
+    input.iter().map(|i| *i - min).sum()
+}
+
+type InputPart3<'a> = Vec<isize>;
+fn parse_input_part3(input: &'_ str) -> parse::Result<InputPart3<'_>> {
+    input.lines().map(|l| parse::number(input, l)).collect()
+}
+
+fn p3(input: &InputPart3) -> isize {
+    let mut input = input.clone();
+    input.sort();
+    let median = input[input.len() / 2];
+    input.iter().map(|i| (*i - median).abs()).sum::<isize>()
+}
+
+struct Quest;
+
+impl Solution for Quest {
+    type Part1<'a> = InputPart1<'a>;
+    type Part2<'a> = InputPart2<'a>;
+    type Part3<'a> = InputPart3<'a>;
+
+    fn parse_part1(input: &str) -> anyhow::Result<Self::Part1<'_>> {
+        Ok(parse_input_part1(input)?)
+    }
+
+    fn parse_part2(input: &str) -> anyhow::Result<Self::Part2<'_>> {
+        Ok(parse_input_part2(input)?)
+    }
+
+    fn parse_part3(input: &str) -> anyhow::Result<Self::Part3<'_>> {
+        Ok(parse_input_part3(input)?)
+    }
+
+    fn p1(input: &mut Self::Part1<'_>) -> impl Display {
+        p1(input)
+    }
+
+    fn p2(input: &mut Self::Part2<'_>) -> impl Display {
+        p2(input)
+    }
+
+    fn p3(input: &mut Self::Part3<'_>) -> impl Display {
+        p3(input)
+    }
+}
+
+pub(crate) const ENTRY: Entry = Entry::new::<Quest>(crate::EVENT, 4);
@@ -0,0 +1,347 @@
+use std::{collections::VecDeque, fmt::Display};
+
+use ec_common::{
+    Color, Entry, Picture, Point, Recorder, Simulation, Solution, Stepper, cycle::Sequence, parse,
+};
+
+use rustc_hash::FxHashMap;
+
+type InputPart1<'a> = Vec<VecDeque<usize>>;
+fn parse_input_part1(input: &'_ str) -> parse::Result<InputPart1<'_>> {
+    // There's a column for each number on the first line.
+    let columns = input
+        .trim()
+        .lines()
+        .next()
+        .map_or(0, |l| l.split_whitespace().count());
+    input
+        .trim()
+        .lines()
+        .try_fold(vec![VecDeque::new(); columns], |mut acc, l| {
+            let mut pp = l.split_whitespace();
+            for column in acc.iter_mut() {
+                let p = parse::next(input, l, &mut pp, "column")?;
+                column.push_back(parse::number(input, p)?);
+            }
+            Ok(acc)
+        })
+}
+
+fn step(input: &mut [VecDeque<usize>], turn: usize) -> usize {
+    // turn is zero based.
+    let turn = turn % input.len();
+    let clapper = input[turn].pop_front().unwrap();
+    let column = (turn + 1) % input.len();
+    let circuit_len = input[column].len() * 2;
+    let pos = (clapper - 1) % circuit_len;
+    let pos = pos.min(circuit_len - pos);
+    input[column].insert(pos, clapper);
+    shout(input)
+}
+
+// The clappers at the front of each column, one after the other.
+fn shout(input: &[VecDeque<usize>]) -> usize {
+    input.iter().map(|v| v[0]).fold(0usize, |acc, i| {
+        acc * 10usize.pow((i as f64).log10().floor() as u32 + 1) + i
+    })
+}
+
+fn p1(input: &mut InputPart1) -> usize {
+    (0..10).map(|i| step(input, i)).last().unwrap()
+}
+
+type InputPart2<'a> = Vec<VecDeque<usize>>;
+fn parse_input_part2(input: &'_ str) -> parse::Result<InputPart2<'_>> {
+    parse_input_part1(input)
+}
+
+fn p2(input: &mut InputPart2) -> usize {
+    let mut shouts: FxHashMap<usize, usize> = FxHashMap::default();
+    (0..)
+        .find_map(|i| {
+            let shout = step(input, i);
+            let s = shouts.entry(shout).or_default();
+            *s += 1;
+            if *s == 2024 {
+                Some(shout * (i + 1))
+            } else {
+                None
+            }
+        })
+        .unwrap()
+}
+
+type InputPart3<'a> = Vec<VecDeque<usize>>;
+fn parse_input_part3(input: &'_ str) -> parse::Result<InputPart3<'_>> {
+    parse_input_part1(input)
+}
+
+fn str(input: &InputPart3) -> String {
+    input
+        .iter()
+        .map(|r| {
+            r.iter()
+                .map(|n| n.to_string())
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// Once the dance repeats, every shout it will ever make has been made. Who claps next is part of
+// the state, but the columns can only be back where they were after a whole round of turns anyway.
+fn p3(input: &mut InputPart3) -> usize {
+    let sequence = Sequence::new(
+        (input.clone(), 0),
+        |(columns, turn)| {
+            let mut columns = columns.clone();
+            step(&mut columns, *turn);
+            let turn = (turn + 1) % columns.len();
+            (columns, turn)
+        },
+        |(columns, _)| shout(columns),
+    );
+    let cycle = sequence.cycle();
+    (1..=cycle.start + cycle.len)
+        .map(|n| sequence.value(n))
+        .max()
+        .unwrap()
+}
+
+// Each column of clappers, shaded by their numbers. The front row is who shouts.
+fn picture(columns: &[VecDeque<usize>]) -> Picture {
+    let max = columns.iter().flatten().max().copied().unwrap_or(1) as f64;
+    let mut picture = Picture::new();
+    for (x, column) in (0..).zip(columns) {
+        for (y, &clapper) in (0..).zip(column) {
+            let color = Color::YELLOW.mix(Color::BLUE, clapper as f64 / max);
+            picture.cell(Point::new(x * 2, y), color);
+        }
+    }
+    picture
+}
+
+// Records the columns each round until the part would have stopped. Part 2 notes the shout that
+// gets to 2024 and part 3 notes when the dance starts repeating.
+fn animate(part: u8, columns: &mut InputPart1, recorder: &mut Recorder) {
+    let mut shouts: FxHashMap<usize, usize> = FxHashMap::default();
+    let mut states: FxHashMap<String, usize> = FxHashMap::default();
+    states.insert(str(columns), 0);
+
+    recorder.record(0, || picture(columns));
+    for round in 1.. {
+        let shout = step(columns, round - 1);
+        recorder.record(round, || picture(columns));
+
+        let finished = match part {
+            1 => round == 10,
+            2 => {
+                let count = shouts.entry(shout).or_default();
+                *count += 1;
+                if *count == 2024 {
+                    recorder.note(format!(
+                        "{shout} is shouted for the 2024th time in round {round}"
+                    ));
+                }
+                *count == 2024
+            }
+            _ => match states.insert(str(columns), round) {
+                Some(start) => {
+                    recorder.note(format!("round {round} is the same as round {start}"));
+                    true
+                }
+                None => false,
+            },
+        };
+        if finished || recorder.done(round) {
+            break;
+        }
+    }
+}
+
+// The dance for `ec debug`, one clapper at a time.
+#[derive(Debug, Clone)]
+struct Dance {
+    columns: Vec<VecDeque<usize>>,
+    turn: usize,
+    shout: Option<usize>,
+    highest: usize,
+}
+
+impl Simulation for Dance {
+    fn state(&self) -> String {
+        let width = self
+            .columns
+            .iter()
+            .flatten()
+            .max()
+            .map_or(1, |n| n.to_string().len());
+        let rows = self.columns.iter().map(|c| c.len()).max().unwrap_or(0);
+        (0..rows)
+            .map(|y| {
+                self.columns
+                    .iter()
+                    .map(|c| {
+                        c.get(y)
+                            .map_or(" ".repeat(width), |n| format!("{n:>width$}"))
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn step(&mut self) -> bool {
+        let shout = step(&mut self.columns, self.turn);
+        self.turn += 1;
+        self.shout = Some(shout);
+        self.highest = self.highest.max(shout);
+        true
+    }
+
+    fn status(&self) -> String {
+        match self.shout {
+            Some(shout) => format!(
+                "column {} clapped, shouting {shout} (highest {})",
+                (self.turn - 1) % self.columns.len() + 1,
+                self.highest
+            ),
+            None => "column 1 claps next".to_string(),
+        }
+    }
+}
+
+struct Quest;
+
+impl Solution for Quest {
+    type Part1<'a> = InputPart1<'a>;
+    type Part2<'a> = InputPart2<'a>;
+    type Part3<'a> = InputPart3<'a>;
+
+    fn parse_part1(input: &str) -> anyhow::Result<Self::Part1<'_>> {
+        Ok(parse_input_part1(input)?)
+    }
+
+    fn parse_part2(input: &str) -> anyhow::Result<Self::Part2<'_>> {
+        Ok(parse_input_part2(input)?)
+    }
+
+    fn parse_part3(input: &str) -> anyhow::Result<Self::Part3<'_>> {
+        Ok(parse_input_part3(input)?)
+    }
+
+    fn p1(input: &mut Self::Part1<'_>) -> impl Display {
+        p1(input)
+    }
+
+    fn p2(input: &mut Self::Part2<'_>) -> impl Display {
+        p2(input)
+    }
+
+    fn p3(input: &mut Self::Part3<'_>) -> impl Display {
+        p3(input)
+    }
+
+    fn animate(part: u8, input: &str, recorder: &mut Recorder) -> anyhow::Result<()> {
+        animate(part, &mut parse_input_part1(input)?, recorder);
+        Ok(())
+    }
+
+    fn simulate(_part: u8, input: &str) -> anyhow::Result<Option<Stepper>> {
+        Ok(Some(Stepper::new(Dance {
+            columns: parse_input_part1(input)?,
+            turn: 0,
+            shout: None,
+            highest: 0,
+        })))
+    }
+}
+
+pub(crate) const ENTRY: Entry = Entry::new::<Quest>(crate::EVENT, 5);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_p1() {
+        let input = "2 3 4 5
+3 4 5 2
+4 5 2 3
+5 2 3 4";
+        let mut input = parse_input_part1(input).unwrap();
+        assert_eq!(
+            input,
+            vec![
+                vec![2, 3, 4, 5],
+                vec![3, 4, 5, 2],
+                vec![4, 5, 2, 3],
+                vec![5, 2, 3, 4]
+            ]
+        );
+        assert_eq!(p1(&mut input), 2323);
+    }
+
+    #[test]
+    fn test_three_columns() {
+        let mut input = parse_input_part1("2 3 4\n5 6 7").unwrap();
+        assert_eq!(input.len(), 3);
+        assert_eq!(p1(&mut input), 457);
+    }
+
+    #[test]
+    fn test_p2() {
+        let input = "2 3 4 5
+6 7 8 9";
+        let mut input = parse_input_part2(input).unwrap();
+        assert_eq!(p2(&mut input), 50877075);
+    }
+
+    #[test]
+    fn test_p3() {
+        let input = "2 3 4 5
+6 7 8 9";
+        let mut input = parse_input_part3(input).unwrap();
+        assert_eq!(p3(&mut input), 6584);
+    }
+
+    #[test]
+    fn test_simulate() {
+        let columns = parse_input_part1("2 3 4 5\n3 4 5 2\n4 5 2 3\n5 2 3 4").unwrap();
+        let mut stepper = Stepper::new(Dance {
+            columns,
+            turn: 0,
+            shout: None,
+            highest: 0,
+        });
+        stepper.seek(10);
+        assert_eq!(
+            stepper.status(),
+            "column 2 clapped, shouting 2323 (highest 4452)"
+        );
+        assert_eq!(stepper.state().lines().next(), Some("2 3 2 3"));
+    }
+
+    #[test]
+    fn test_animate() {
+        // Part 1 stops after its 10 rounds.
+        let mut input = parse_input_part1("2 3 4 5\n3 4 5 2\n4 5 2 3\n5 2 3 4").unwrap();
+        let mut recorder = Recorder::new(0..100);
+        animate(1, &mut input, &mut recorder);
+        assert_eq!(recorder.len(), 11);
+        assert!(recorder.notes().is_empty());
+
+        // Part 3 notes when the dance starts repeating.
+        let mut input = parse_input_part3("2 3 4 5\n6 7 8 9").unwrap();
+        let mut recorder = Recorder::new(0..1_000_000);
+        animate(3, &mut input, &mut recorder);
+        assert_eq!(recorder.notes().len(), 1);
+        assert!(
+            recorder.notes()[0].contains("is the same as round"),
+            "{:?}",
+            recorder.notes()
+        );
+    }
+}
@@ -0,0 +1,174 @@
+use std::fmt::Display;
+
+use ec_common::{Entry, Solution, parse, search::bfs};
+
+use rustc_hash::FxHashMap;
+
+type InputPart1<'a> = FxHashMap<&'a str, Vec<&'a str>>;
+fn parse_input_part1(input: &'_ str) -> parse::Result<InputPart1<'_>> {
+    input
+        .lines()
+        .map(|l| {
+            let (k, vv) = parse::split_once(input, l, ":")?;
+            Ok((k, vv.split(',').collect()))
+        })
+        .collect()
+}
+
+// Search the tree from RR to find the path to every '@', never going through the branches in
+// `avoid`. A branch with more than one '@' on it has a path for each of them.
+fn fruit_paths<'a>(t: &FxHashMap<&'a str, Vec<&'a str>>, avoid: &[&str]) -> Vec<Vec<&'a str>> {
+    let search = bfs(
+        ["RR"],
+        |branch| {
+            t.get(branch)
+                .into_iter()
+                .flatten()
+                .copied()
+                .filter(|child| !avoid.contains(child))
+        },
+        |_| false,
+    );
+    t.iter()
+        .flat_map(|(branch, children)| {
+            let fruits = children.iter().filter(|&&c| c == "@").count();
+            let path = search.path(branch).map(|mut path| {
+                path.push("@");
+                path
+            });
+            path.into_iter()
+                .flat_map(move |path| std::iter::repeat_n(path, fruits))
+        })
+        .collect()
+}
+
+fn p1(input: &InputPart1) -> String {
+    let found = fruit_paths(input, &[]);
+
+    // Map paths (index) to their len.
+    let counts = found.iter().enumerate().fold(
+        FxHashMap::default(),
+        |mut acc: FxHashMap<usize, Vec<usize>>, (i, f)| {
+            acc.entry(f.len()).or_default().push(i);
+            acc
+        },
+    );
+
+    // Return the one with the unique path.
+    counts
+        .iter()
+        .find(|(_, vv)| vv.len() == 1)
+        .map(|(_, vv)| found[vv[0]].join(""))
+        .unwrap()
+}
+
+type InputPart2<'a> = InputPart1<'a>;
+fn parse_input_part2(input: &'_ str) -> parse::Result<InputPart2<'_>> {
+    parse_input_part1(input)
+}
+
+// Find the path that's unique (similar to p1) but return just the first character.
+fn find_unique(paths: &[Vec<&str>]) -> String {
+    // Put the index of each path in the bucket of similar lengths.
+    let counts = paths.iter().enumerate().fold(
+        FxHashMap::default(),
+        |mut acc: FxHashMap<usize, Vec<usize>>, (i, f)| {
+            acc.entry(f.len()).or_default().push(i);
+            acc
+        },
+    );
+
+    // Turn them into string (p2 and p3 just take first char).
+    counts
+        .iter()
+        .find(|(_, vv)| vv.len() == 1)
+        .map(|(_, vv)| {
+            paths[vv[0]]
+                .iter()
+                .map(|n| n.chars().next().unwrap())
+                .collect::<String>()
+        })
+        .unwrap()
+}
+
+fn p2(input: &InputPart2) -> String {
+    find_unique(&fruit_paths(input, &[]))
+}
+
+type InputPart3<'a> = InputPart1<'a>;
+fn parse_input_part3(input: &'_ str) -> parse::Result<InputPart3<'_>> {
+    parse_input_part1(input)
+}
+
+fn p3(input: &InputPart3) -> String {
+    // Ants and bugs have gotten into some of the branches, so we can't go that way.
+    find_unique(&fruit_paths(input, &["ANT", "BUG"]))
+}
+
+struct Quest;
+
+impl Solution for Quest {
+    type Part1<'a> = InputPart1<'a>;
+    type Part2<'a> = InputPart2<'a>;
+    type Part3<'a> = InputPart3<'a>;
+
+    fn parse_part1(input: &str) -> anyhow::Result<Self::Part1<'_>> {
+        Ok(parse_input_part1(input)?)
+    }
+
+    fn parse_part2(input: &str) -> anyhow::Result<Self::Part2<'_>> {
+        Ok(parse_input_part2(input)?)
+    }
+
+    fn parse_part3(input: &str) -> anyhow::Result<Self::Part3<'_>> {
+        Ok(parse_input_part3(input)?)
+    }
+
+    fn p1(input: &mut Self::Part1<'_>) -> impl Display {
+        p1(input)
+    }
+
+    fn p2(input: &mut Self::Part2<'_>) -> impl Display {
+        p2(input)
+    }
+
+    fn p3(input: &mut Self::Part3<'_>) -> impl Display {
+        p3(input)
+    }
+}
+
+pub(crate) const ENTRY: Entry = Entry::new::<Quest>(crate::EVENT, 6);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_p1() {
+        let input =
+            parse_input_part1("RR:A,B,C\nA:D,E\nB:F,@\nC:G,H\nD:@\nE:@\nF:@\nG:@\nH:@").unwrap();
+        assert_eq!(p1(&input), "RRB@");
+
+        // C has two fruits, so its path isn't the only one that long any more.
+        let input = parse_input_part1("RR:A,B,C\nA:D\nB:@\nC:@,@\nD:@").unwrap();
+        assert_eq!(fruit_paths(&input, &[]).len(), 4);
+        assert_eq!(p1(&input), "RRAD@");
+    }
+
+    #[test]
+    fn test_p2() {
+        let input =
+            parse_input_part2("RR:A,B,C\nA:D,E\nB:F,@\nC:G,H\nD:@\nE:@\nF:@\nG:@\nH:@").unwrap();
+        assert_eq!(p2(&input), "RB@");
+    }
+
+    #[test]
+    fn test_p3() {
+        // The way through ANT is as long as E's, so E's is only the odd one out avoiding the ant.
+        let input =
+            parse_input_part3("RR:A,C,E,ANT\nA:B\nB:@\nC:D\nD:@\nE:F\nF:G\nG:@\nANT:X\nX:Y\nY:@")
+                .unwrap();
+        assert_eq!(fruit_paths(&input, &[]).len(), 4);
+        assert_eq!(p3(&input), "REFG@");
+    }
+}
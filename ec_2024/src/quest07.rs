@@ -1,28 +1,29 @@
-use rayon::prelude::*;
-use rustc_hash::FxHashSet;
 use std::{
+    fmt::Display,
     iter::{once, repeat_n},
-    time::Instant,
 };
 
-const INPUT_PART1: &str = include_str!("inputs/quest07-1.txt");
+use ec_common::{Entry, ParseError, Solution, parse};
+
+use rayon::prelude::*;
+use rustc_hash::FxHashSet;
+
 type InputPart1<'a> = Vec<(&'a str, Vec<i64>)>;
-fn parse_input_part1(input: &'_ str) -> InputPart1<'_> {
+fn parse_input_part1(input: &'_ str) -> parse::Result<InputPart1<'_>> {
     input
         .lines()
         .map(|l| {
-            let (k, vv) = l.split_once(':').unwrap();
-            (
-                k,
-                vv.split(',')
-                    .map(|v| match v {
-                        "+" => 1,
-                        "=" => 0,
-                        "-" => -1,
-                        _ => panic!("sad {}", v),
-                    })
-                    .collect(),
-            )
+            let (k, vv) = parse::split_once(input, l, ":")?;
+            let vv = vv
+                .split(',')
+                .map(|v| match v {
+                    "+" => Ok(1),
+                    "=" => Ok(0),
+                    "-" => Ok(-1),
+                    _ => Err(ParseError::at(input, v, format!("bad action '{v}'"))),
+                })
+                .collect::<parse::Result<_>>()?;
+            Ok((k, vv))
         })
         .collect()
 }
@@ -42,13 +43,12 @@ fn p1(input: &InputPart1) -> String {
             )
         })
         .collect::<Vec<_>>();
-    results.sort_by(|a, b| b.1.cmp(&a.1));
+    results.sort_by_key(|r| std::cmp::Reverse(r.1));
     results.iter().map(|(k, _)| **k).collect::<String>()
 }
 
-const INPUT_PART2: &str = include_str!("inputs/quest07-2.txt");
 type InputPart2<'a> = InputPart1<'a>;
-fn parse_input_part2(input: &'_ str) -> InputPart2<'_> {
+fn parse_input_part2(input: &'_ str) -> parse::Result<InputPart2<'_>> {
     parse_input_part1(input)
 }
 
@@ -156,7 +156,7 @@ fn p2(input: &InputPart2) -> String {
             )
         })
         .collect::<Vec<_>>();
-    results.sort_by(|a, b| b.1.cmp(&a.1));
+    results.sort_by_key(|r| std::cmp::Reverse(r.1));
     results.iter().map(|(k, _)| **k).collect::<String>()
 }
 
@@ -171,9 +171,8 @@ const TRACK_P3: &str = "S+= +=-== +=++=     =+=+=--=    =-= ++=     +=-  =+=++=-
 -               = + + =   +  -  = + = = +   =        +     =          -
 --==++++==+=+++-= =-= =-+-=  =+-= =-= =--   +=++=+++==     -=+=++==+++-";
 
-const INPUT_PART3: &str = include_str!("inputs/quest07-3.txt");
 type InputPart3<'a> = InputPart1<'a>;
-fn parse_input_part3(input: &'_ str) -> InputPart3<'_> {
+fn parse_input_part3(input: &'_ str) -> parse::Result<InputPart3<'_>> {
     parse_input_part1(input)
 }
 
@@ -250,23 +249,40 @@ fn p3(input: &InputPart3) -> usize {
         .count()
 }
 
-fn main() {
-    let now = Instant::now();
-    let input = parse_input_part1(INPUT_PART1);
-    let solution = p1(&input);
-    println!("p1 {:?} {}", now.elapsed(), solution);
-
-    let now = Instant::now();
-    let input = parse_input_part2(INPUT_PART2);
-    let solution = p2(&input);
-    println!("p2 {:?} {}", now.elapsed(), solution);
-
-    let now = Instant::now();
-    let input = parse_input_part3(INPUT_PART3);
-    let solution = p3(&input);
-    println!("p3 {:?} {}", now.elapsed(), solution);
+struct Quest;
+
+impl Solution for Quest {
+    type Part1<'a> = InputPart1<'a>;
+    type Part2<'a> = InputPart2<'a>;
+    type Part3<'a> = InputPart3<'a>;
+
+    fn parse_part1(input: &str) -> anyhow::Result<Self::Part1<'_>> {
+        Ok(parse_input_part1(input)?)
+    }
+
+    fn parse_part2(input: &str) -> anyhow::Result<Self::Part2<'_>> {
+        Ok(parse_input_part2(input)?)
+    }
+
+    fn parse_part3(input: &str) -> anyhow::Result<Self::Part3<'_>> {
+        Ok(parse_input_part3(input)?)
+    }
+
+    fn p1(input: &mut Self::Part1<'_>) -> impl Display {
+        p1(input)
+    }
+
+    fn p2(input: &mut Self::Part2<'_>) -> impl Display {
+        p2(input)
+    }
+
+    fn p3(input: &mut Self::Part3<'_>) -> impl Display {
+        p3(input)
+    }
 }
 
+pub(crate) const ENTRY: Entry = Entry::new::<Quest>(crate::EVENT, 7);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -277,7 +293,7 @@ mod tests {
 B:+,=,-,+
 C:=,-,+,+
 D:=,=,=,+";
-        let input = parse_input_part1(input);
+        let input = parse_input_part1(input).unwrap();
         assert_eq!(p1(&input), "BDCA");
     }
 }
@@ -1,9 +1,10 @@
-use std::time::Instant;
+use std::fmt::Display;
+
+use ec_common::{Entry, Solution, parse};
 
-const INPUT_PART1: &str = include_str!("inputs/quest08-1.txt");
 type InputPart1<'a> = u64;
-fn parse_input_part1(input: &'_ str) -> InputPart1<'_> {
-    input.trim().parse::<u64>().unwrap()
+fn parse_input_part1(input: &'_ str) -> parse::Result<InputPart1<'_>> {
+    parse::number(input, input.trim())
 }
 
 fn p1(input: &InputPart1) -> u64 {
@@ -56,32 +57,46 @@ fn p3(input: &InputPart2, acolytes: u64, available: u64) -> u64 {
     unreachable!()
 }
 
-fn main() {
-    let now = Instant::now();
-    let input = parse_input_part1(INPUT_PART1);
-    let solution = p1(&input);
-    println!("p1 {:?} {}", now.elapsed(), solution);
-
-    let now = Instant::now();
-    let input = parse_input_part2(INPUT_PART2);
-    let solution = p2(&input, 1111, 20240000);
-    println!("p2 {:?} {}", now.elapsed(), solution);
-
-    let now = Instant::now();
-    let input = parse_input_part3(INPUT_PART3);
-    let solution = p3(&input, 10, 202400000);
-    println!("p3 {:?} {}", now.elapsed(), solution);
+struct Quest;
+
+impl Solution for Quest {
+    type Part1<'a> = InputPart1<'a>;
+    type Part2<'a> = InputPart2<'a>;
+    type Part3<'a> = InputPart3<'a>;
+
+    fn parse_part1(input: &str) -> anyhow::Result<Self::Part1<'_>> {
+        Ok(parse_input_part1(input)?)
+    }
+
+    fn parse_part2(input: &str) -> anyhow::Result<Self::Part2<'_>> {
+        Ok(parse_input_part2(input)?)
+    }
+
+    fn parse_part3(input: &str) -> anyhow::Result<Self::Part3<'_>> {
+        Ok(parse_input_part3(input)?)
+    }
+
+    fn p1(input: &mut Self::Part1<'_>) -> impl Display {
+        p1(input)
+    }
+
+    fn p2(input: &mut Self::Part2<'_>) -> impl Display {
+        p2(input, 1111, 20240000)
+    }
+
+    fn p3(input: &mut Self::Part3<'_>) -> impl Display {
+        p3(input, 10, 202400000)
+    }
 }
 
-const INPUT_PART2: &str = include_str!("inputs/quest08-2.txt");
+pub(crate) const ENTRY: Entry = Entry::new::<Quest>(crate::EVENT, 8);
+
 type InputPart2<'a> = InputPart1<'a>;
-fn parse_input_part2(input: &'_ str) -> InputPart2<'_> {
+fn parse_input_part2(input: &'_ str) -> parse::Result<InputPart2<'_>> {
     parse_input_part1(input)
 }
 
-const INPUT_PART3: &str = include_str!("inputs/quest08-3.txt");
 type InputPart3<'a> = InputPart1<'a>;
-fn parse_input_part3(input: &'_ str) -> InputPart3<'_> {
+fn parse_input_part3(input: &'_ str) -> parse::Result<InputPart3<'_>> {
     parse_input_part1(input)
 }
-
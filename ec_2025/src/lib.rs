@@ -0,0 +1,52 @@
+//! Solutions for the [2025 event](https://everybody.codes/event/2025/quests).
+
+use ec_common::Entry;
+
+mod quest01;
+mod quest02;
+mod quest03;
+mod quest04;
+mod quest05;
+mod quest06;
+mod quest07;
+mod quest08;
+mod quest09;
+mod quest10;
+mod quest11;
+mod quest12;
+mod quest13;
+mod quest14;
+mod quest15;
+mod quest16;
+mod quest17;
+mod quest18;
+mod quest19;
+mod quest20;
+
+pub const EVENT: u16 = 2025;
+
+/// All of the quests solved so far.
+pub fn quests() -> Vec<Entry> {
+    vec![
+        quest01::ENTRY,
+        quest02::ENTRY,
+        quest03::ENTRY,
+        quest04::ENTRY,
+        quest05::ENTRY,
+        quest06::ENTRY,
+        quest07::ENTRY,
+        quest08::ENTRY,
+        quest09::ENTRY,
+        quest10::ENTRY,
+        quest11::ENTRY,
+        quest12::ENTRY,
+        quest13::ENTRY,
+        quest14::ENTRY,
+        quest15::ENTRY,
+        quest16::ENTRY,
+        quest17::ENTRY,
+        quest18::ENTRY,
+        quest19::ENTRY,
+        quest20::ENTRY,
+    ]
+}
@@ -0,0 +1,88 @@
+use std::fmt::Display;
+
+use ec_common::{Entry, ParseError, Solution, parse};
+
+type InputPart1<'a> = (Vec<&'a str>, Vec<isize>);
+fn parse_input_part1(input: &'_ str) -> parse::Result<InputPart1<'_>> {
+    let (names, moves) = parse::split_once(input, input, "\n\n")?;
+    let names = names.split(',').collect();
+    let moves = moves
+        .split(',')
+        .map(|p| {
+            let dir = parse::char(input, p)?;
+            let steps = parse::number::<isize>(input, &p[dir.len_utf8()..])?;
+            match dir {
+                'R' => Ok(steps),
+                'L' => Ok(-steps),
+                _ => Err(ParseError::at(input, p, format!("bad direction '{dir}'"))),
+            }
+        })
+        .collect::<parse::Result<_>>()?;
+    Ok((names, moves))
+}
+
+fn p1<'a>((names, moves): &'a InputPart1) -> &'a str {
+    let max = (names.len() - 1) as isize;
+    let pos = moves
+        .iter()
+        .fold(0isize, |acc, delta| (acc + delta).clamp(0, max));
+    names[pos as usize]
+}
+
+fn p2<'a>((names, moves): &'a InputPart2) -> &'a str {
+    let pos = moves.iter().sum::<isize>().rem_euclid(names.len() as isize);
+    names[pos as usize]
+}
+
+fn p3<'a>((names, moves): &'a mut InputPart3) -> &'a str {
+    moves.iter().for_each(|delta| {
+        let pos = delta.rem_euclid(names.len() as isize);
+        names.swap(0, pos as usize);
+    });
+
+    names[0]
+}
+
+struct Quest;
+
+impl Solution for Quest {
+    type Part1<'a> = InputPart1<'a>;
+    type Part2<'a> = InputPart2<'a>;
+    type Part3<'a> = InputPart3<'a>;
+
+    fn parse_part1(input: &str) -> anyhow::Result<Self::Part1<'_>> {
+        Ok(parse_input_part1(input)?)
+    }
+
+    fn parse_part2(input: &str) -> anyhow::Result<Self::Part2<'_>> {
+        Ok(parse_input_part2(input)?)
+    }
+
+    fn parse_part3(input: &str) -> anyhow::Result<Self::Part3<'_>> {
+        Ok(parse_input_part3(input)?)
+    }
+
+    fn p1(input: &mut Self::Part1<'_>) -> impl Display {
+        p1(input)
+    }
+
+    fn p2(input: &mut Self::Part2<'_>) -> impl Display {
+        p2(input)
+    }
+
+    fn p3(input: &mut Self::Part3<'_>) -> impl Display {
+        p3(input)
+    }
+}
+
+pub(crate) const ENTRY: Entry = Entry::new::<Quest>(crate::EVENT, 1);
+
+type InputPart2<'a> = InputPart1<'a>;
+fn parse_input_part2(input: &'_ str) -> parse::Result<InputPart2<'_>> {
+    parse_input_part1(input)
+}
+
+type InputPart3<'a> = InputPart1<'a>;
+fn parse_input_part3(input: &'_ str) -> parse::Result<InputPart3<'_>> {
+    parse_input_part1(input)
+}
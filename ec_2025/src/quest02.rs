@@ -0,0 +1,100 @@
+use std::{fmt::Display, ops::RangeInclusive};
+
+use ec_common::{Entry, Solution, parse};
+
+use itertools::Itertools;
+use num::complex::Complex;
+use rayon::prelude::*;
+
+type InputPart1<'a> = Complex<isize>;
+fn parse_input_part1(input: &'_ str) -> parse::Result<InputPart1<'_>> {
+    let a = input.trim().trim_start_matches("A=[").trim_end_matches("]");
+    let (r, i) = parse::split_once(input, a, ",")?;
+    Ok(Complex::new(
+        parse::number(input, r)?,
+        parse::number(input, i)?,
+    ))
+}
+
+fn p1(a: InputPart1) -> String {
+    let mut r = Complex::new(0, 0);
+    (0..3).for_each(|_| {
+        r *= r;
+        r /= 10;
+        r += a;
+    });
+    format!("[{},{}]", r.re, r.im)
+}
+
+const VALID_RANGE: RangeInclusive<isize> = -1_000_000..=1_000_000;
+
+fn cycle(a: Complex<isize>, x: isize, y: isize) -> bool {
+    let p = a + Complex::new(x, y);
+    let mut r = Complex::new(0, 0);
+    (0..100).all(|_| {
+        r *= r;
+        r /= 100_000;
+        r += p;
+        VALID_RANGE.contains(&r.re) && VALID_RANGE.contains(&r.im)
+    })
+}
+
+fn p2(a: InputPart2) -> usize {
+    (0..=1000)
+        .step_by(10)
+        .cartesian_product((0..=1_000).step_by(10))
+        .filter(|(x, y)| cycle(a, *x, *y))
+        .count()
+}
+
+fn p3(a: InputPart3) -> usize {
+    (0..=1000)
+        .into_par_iter()
+        .flat_map(|x| (0..=1000).into_par_iter().map(move |y| (x, y)))
+        .filter(|(x, y)| cycle(a, *x, *y))
+        .count()
+}
+
+struct Quest;
+
+impl Solution for Quest {
+    type Part1<'a> = InputPart1<'a>;
+    type Part2<'a> = InputPart2<'a>;
+    type Part3<'a> = InputPart3<'a>;
+
+    fn parse_part1(input: &str) -> anyhow::Result<Self::Part1<'_>> {
+        Ok(parse_input_part1(input)?)
+    }
+
+    fn parse_part2(input: &str) -> anyhow::Result<Self::Part2<'_>> {
+        Ok(parse_input_part2(input)?)
+    }
+
+    fn parse_part3(input: &str) -> anyhow::Result<Self::Part3<'_>> {
+        Ok(parse_input_part3(input)?)
+    }
+
+    fn p1(input: &mut Self::Part1<'_>) -> impl Display {
+        p1(*input)
+    }
+
+    fn p2(input: &mut Self::Part2<'_>) -> impl Display {
+        p2(*input)
+    }
+
+    fn p3(input: &mut Self::Part3<'_>) -> impl Display {
+        p3(*input)
+    }
+}
+
+pub(crate) const ENTRY: Entry = Entry::new::<Quest>(crate::EVENT, 2);
+
+type InputPart2<'a> = InputPart1<'a>;
+fn parse_input_part2(input: &'_ str) -> parse::Result<InputPart2<'_>> {
+    parse_input_part1(input)
+}
+
+type InputPart3<'a> = InputPart1<'a>;
+fn parse_input_part3(input: &'_ str) -> parse::Result<InputPart3<'_>> {
+    parse_input_part1(input)
+}
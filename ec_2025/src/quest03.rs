@@ -0,0 +1,75 @@
+use std::fmt::Display;
+
+use ec_common::{Entry, Solution, parse};
+
+use itertools::Itertools;
+use rustc_hash::FxHashMap;
+
+type InputPart1<'a> = Vec<isize>;
+fn parse_input_part1(input: &'_ str) -> parse::Result<InputPart1<'_>> {
+    input
+        .trim()
+        .split(',')
+        .map(|p| parse::number(input, p))
+        .collect()
+}
+
+fn p1(input: &InputPart1) -> isize {
+    input.iter().sorted().dedup().sum()
+}
+
+fn p2(input: &InputPart2) -> isize {
+    input.iter().sorted().dedup().take(20).sum()
+}
+
+fn p3(input: &InputPart3) -> isize {
+    let counts = input.iter().fold(FxHashMap::default(), |mut acc, i| {
+        *acc.entry(i).or_default() += 1;
+        acc
+    });
+    *counts.values().max().unwrap()
+}
+
+struct Quest;
+
+impl Solution for Quest {
+    type Part1<'a> = InputPart1<'a>;
+    type Part2<'a> = InputPart2<'a>;
+    type Part3<'a> = InputPart3<'a>;
+
+    fn parse_part1(input: &str) -> anyhow::Result<Self::Part1<'_>> {
+        Ok(parse_input_part1(input)?)
+    }
+
+    fn parse_part2(input: &str) -> anyhow::Result<Self::Part2<'_>> {
+        Ok(parse_input_part2(input)?)
+    }
+
+    fn parse_part3(input: &str) -> anyhow::Result<Self::Part3<'_>> {
+        Ok(parse_input_part3(input)?)
+    }
+
+    fn p1(input: &mut Self::Part1<'_>) -> impl Display {
+        p1(input)
+    }
+
+    fn p2(input: &mut Self::Part2<'_>) -> impl Display {
+        p2(input)
+    }
+
+    fn p3(input: &mut Self::Part3<'_>) -> impl Display {
+        p3(input)
+    }
+}
+
+pub(crate) const ENTRY: Entry = Entry::new::<Quest>(crate::EVENT, 3);
+
+type InputPart2<'a> = InputPart1<'a>;
+fn parse_input_part2(input: &'_ str) -> parse::Result<InputPart2<'_>> {
+    parse_input_part1(input)
+}
+
+type InputPart3<'a> = InputPart1<'a>;
+fn parse_input_part3(input: &'_ str) -> parse::Result<InputPart3<'_>> {
+    parse_input_part1(input)
+}
@@ -0,0 +1,86 @@
+use std::fmt::Display;
+
+use ec_common::{Entry, ParseError, Solution, parse};
+
+type InputPart1<'a> = (usize, Vec<(usize, usize)>, usize);
+fn parse_input_part1(input: &'_ str) -> parse::Result<InputPart1<'_>> {
+    let lines = input.trim().lines().collect::<Vec<_>>();
+    let (Some(first), Some(last)) = (lines.first(), lines.last()) else {
+        return Err(ParseError::at(input, input, "expected some gears"));
+    };
+
+    let first = parse::number(input, first)?;
+    let last = parse::number(input, last)?;
+
+    let middle = lines[1..lines.len().max(1) - 1]
+        .iter()
+        .map(|l| match l.split_once('|') {
+            Some((l, r)) => Ok((parse::number(input, l)?, parse::number(input, r)?)),
+            // If we don't have |, we don't care because p1 and p2 don't
+            // care about middle
+            None => Ok((0, 0)),
+        })
+        .collect::<parse::Result<_>>()?;
+
+    Ok((first, middle, last))
+}
+
+fn p1(&(first, _, last): &InputPart1) -> usize {
+    first * 2025 / last
+}
+
+fn p2(&(first, _, last): &InputPart2) -> usize {
+    // We may have some remainder that we'll need to turn to get the last full turn. div_ceil will
+    // solve this for us.
+    let n = last * 10_000_000_000_000;
+    let d = first;
+    n.div_ceil(d)
+}
+
+fn p3(&(first, ref middle, last): &InputPart3) -> usize {
+    middle.iter().fold(first * 100, |acc, (l, r)| acc * r / l) / last
+}
+
+struct Quest;
+
+impl Solution for Quest {
+    type Part1<'a> = InputPart1<'a>;
+    type Part2<'a> = InputPart2<'a>;
+    type Part3<'a> = InputPart3<'a>;
+
+    fn parse_part1(input: &str) -> anyhow::Result<Self::Part1<'_>> {
+        Ok(parse_input_part1(input)?)
+    }
+
+    fn parse_part2(input: &str) -> anyhow::Result<Self::Part2<'_>> {
+        Ok(parse_input_part2(input)?)
+    }
+
+    fn parse_part3(input: &str) -> anyhow::Result<Self::Part3<'_>> {
+        Ok(parse_input_part3(input)?)
+    }
+
+    fn p1(input: &mut Self::Part1<'_>) -> impl Display {
+        p1(input)
+    }
+
+    fn p2(input: &mut Self::Part2<'_>) -> impl Display {
+        p2(input)
+    }
+
+    fn p3(input: &mut Self::Part3<'_>) -> impl Display {
+        p3(input)
+    }
+}
+
+pub(crate) const ENTRY: Entry = Entry::new::<Quest>(crate::EVENT, 4);
+
+type InputPart2<'a> = InputPart1<'a>;
+fn parse_input_part2(input: &'_ str) -> parse::Result<InputPart2<'_>> {
+    parse_input_part1(input)
+}
+
+type InputPart3<'a> = (usize, Vec<(usize, usize)>, usize);
+fn parse_input_part3(input: &'_ str) -> parse::Result<InputPart3<'_>> {
+    parse_input_part1(input)
+}
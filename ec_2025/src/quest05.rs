@@ -1,22 +1,22 @@
 use std::{
     cmp::Ordering::{Equal, Greater, Less},
-    time::Instant,
+    fmt::Display,
 };
 
-const INPUT_PART1: &str = include_str!("inputs/quest05-1.txt");
+use ec_common::{Entry, Solution, parse};
+
 type InputPart1<'a> = Vec<(usize, Vec<usize>)>;
-fn parse_input_part1(input: &'_ str) -> InputPart1<'_> {
+fn parse_input_part1(input: &'_ str) -> parse::Result<InputPart1<'_>> {
     input
         .lines()
         .map(|l| {
-            l.split_once(':')
-                .map(|(l, r)| {
-                    (
-                        l.parse::<usize>().unwrap(),
-                        r.split(',').map(|n| n.parse::<usize>().unwrap()).collect(),
-                    )
-                })
-                .unwrap()
+            let (l, r) = parse::split_once(input, l, ":")?;
+            Ok((
+                parse::number(input, l)?,
+                r.split(',')
+                    .map(|n| parse::number(input, n))
+                    .collect::<parse::Result<_>>()?,
+            ))
         })
         .collect()
 }
@@ -177,31 +177,46 @@ fn p3(input: &InputPart3) -> usize {
         .sum()
 }
 
-fn main() {
-    let now = Instant::now();
-    let input = parse_input_part1(INPUT_PART1);
-    let solution = p1(&input);
-    println!("p1 {:?} {}", now.elapsed(), solution);
-
-    let now = Instant::now();
-    let input = parse_input_part2(INPUT_PART2);
-    let solution = p2(&input);
-    println!("p2 {:?} {}", now.elapsed(), solution);
-
-    let now = Instant::now();
-    let input = parse_input_part3(INPUT_PART3);
-    let solution = p3(&input);
-    println!("p3 {:?} {}", now.elapsed(), solution);
+struct Quest;
+
+impl Solution for Quest {
+    type Part1<'a> = InputPart1<'a>;
+    type Part2<'a> = InputPart2<'a>;
+    type Part3<'a> = InputPart3<'a>;
+
+    fn parse_part1(input: &str) -> anyhow::Result<Self::Part1<'_>> {
+        Ok(parse_input_part1(input)?)
+    }
+
+    fn parse_part2(input: &str) -> anyhow::Result<Self::Part2<'_>> {
+        Ok(parse_input_part2(input)?)
+    }
+
+    fn parse_part3(input: &str) -> anyhow::Result<Self::Part3<'_>> {
+        Ok(parse_input_part3(input)?)
+    }
+
+    fn p1(input: &mut Self::Part1<'_>) -> impl Display {
+        p1(input)
+    }
+
+    fn p2(input: &mut Self::Part2<'_>) -> impl Display {
+        p2(input)
+    }
+
+    fn p3(input: &mut Self::Part3<'_>) -> impl Display {
+        p3(input)
+    }
 }
 
-const INPUT_PART2: &str = include_str!("inputs/quest05-2.txt");
+pub(crate) const ENTRY: Entry = Entry::new::<Quest>(crate::EVENT, 5);
+
 type InputPart2<'a> = InputPart1<'a>;
-fn parse_input_part2(input: &'_ str) -> InputPart2<'_> {
+fn parse_input_part2(input: &'_ str) -> parse::Result<InputPart2<'_>> {
     parse_input_part1(input)
 }
 
-const INPUT_PART3: &str = include_str!("inputs/quest05-3.txt");
 type InputPart3<'a> = InputPart1<'a>;
-fn parse_input_part3(input: &'_ str) -> InputPart3<'_> {
+fn parse_input_part3(input: &'_ str) -> parse::Result<InputPart3<'_>> {
     parse_input_part1(input)
 }
@@ -1,11 +1,27 @@
-use std::{ops::Index, time::Instant};
+use std::{fmt::Display, ops::Index};
+
+use ec_common::{Entry, ParseError, Solution, parse};
 
 use rustc_hash::FxHashMap;
 
-const INPUT_PART1: &str = include_str!("inputs/quest06-1.txt");
 type InputPart1<'a> = Vec<char>;
-fn parse_input_part1(input: &'_ str) -> InputPart1<'_> {
-    input.trim().chars().collect()
+// Mentors are upper case and novices lower case, for each of the sword (A), archery (B) and magic
+// (C) classes.
+fn parse_input_part1(input: &'_ str) -> parse::Result<InputPart1<'_>> {
+    let people = input.trim();
+    if people.is_empty() {
+        return Err(ParseError::at(
+            input,
+            people,
+            "expected mentors and novices",
+        ));
+    }
+    parse::chars(people)
+        .map(|s| match parse::char(input, s)? {
+            c @ ('A' | 'B' | 'C' | 'a' | 'b' | 'c') => Ok(c),
+            c => Err(ParseError::at(input, s, format!("unexpected '{c}'"))),
+        })
+        .collect()
 }
 
 fn p1(input: &InputPart1) -> usize {
@@ -101,11 +117,10 @@ fn p3(input: &InputPart3, window: isize, repeats: usize) -> usize {
 
     // Our running total and our left and right side of the window.
     let mut total = 0;
-    let mut left = -window;
     let mut right = window;
 
     // Slide through.
-    for cur in 0..input.len() {
+    for (cur, left) in (0..input.len()).zip(-window..) {
         // Update our total
         total += mentors[input[cur]];
 
@@ -119,37 +134,51 @@ fn p3(input: &InputPart3, window: isize, repeats: usize) -> usize {
             input[left.rem_euclid(len) as usize],
             if left < 0 { repeats - 1 } else { repeats },
         );
-        left += 1;
     }
 
     total
 }
 
-fn main() {
-    let now = Instant::now();
-    let input = parse_input_part1(INPUT_PART1);
-    let solution = p1(&input);
-    println!("p1 {:?} {}", now.elapsed(), solution);
-
-    let now = Instant::now();
-    let input = parse_input_part2(INPUT_PART2);
-    let solution = p2(&input);
-    println!("p2 {:?} {}", now.elapsed(), solution);
-
-    let now = Instant::now();
-    let input = parse_input_part3(INPUT_PART3);
-    let solution = p3(&input, 1_000, 1_000);
-    println!("p3 {:?} {}", now.elapsed(), solution);
+struct Quest;
+
+impl Solution for Quest {
+    type Part1<'a> = InputPart1<'a>;
+    type Part2<'a> = InputPart2<'a>;
+    type Part3<'a> = InputPart3<'a>;
+
+    fn parse_part1(input: &str) -> anyhow::Result<Self::Part1<'_>> {
+        Ok(parse_input_part1(input)?)
+    }
+
+    fn parse_part2(input: &str) -> anyhow::Result<Self::Part2<'_>> {
+        Ok(parse_input_part2(input)?)
+    }
+
+    fn parse_part3(input: &str) -> anyhow::Result<Self::Part3<'_>> {
+        Ok(parse_input_part3(input)?)
+    }
+
+    fn p1(input: &mut Self::Part1<'_>) -> impl Display {
+        p1(input)
+    }
+
+    fn p2(input: &mut Self::Part2<'_>) -> impl Display {
+        p2(input)
+    }
+
+    fn p3(input: &mut Self::Part3<'_>) -> impl Display {
+        p3(input, 1_000, 1_000)
+    }
 }
 
-const INPUT_PART2: &str = include_str!("inputs/quest06-2.txt");
+pub(crate) const ENTRY: Entry = Entry::new::<Quest>(crate::EVENT, 6);
+
 type InputPart2<'a> = InputPart1<'a>;
-fn parse_input_part2(input: &'_ str) -> InputPart2<'_> {
+fn parse_input_part2(input: &'_ str) -> parse::Result<InputPart2<'_>> {
     parse_input_part1(input)
 }
 
-const INPUT_PART3: &str = include_str!("inputs/quest06-3.txt");
 type InputPart3<'a> = InputPart1<'a>;
-fn parse_input_part3(input: &'_ str) -> InputPart3<'_> {
+fn parse_input_part3(input: &'_ str) -> parse::Result<InputPart3<'_>> {
     parse_input_part1(input)
 }
@@ -1,30 +1,32 @@
-use std::time::Instant;
+use std::fmt::Display;
+
+use ec_common::{Entry, Solution, parse};
 
 use rustc_hash::FxHashMap;
 
-const INPUT_PART1: &str = include_str!("inputs/quest07-1.txt");
 type InputPart1<'a> = (Vec<&'a str>, FxHashMap<char, Vec<char>>);
-fn parse_input_part1(input: &'_ str) -> InputPart1<'_> {
-    let (names, rules) = input.split_once("\n\n").unwrap();
+fn parse_input_part1(input: &'_ str) -> parse::Result<InputPart1<'_>> {
+    let (names, rules) = parse::split_once(input, input, "\n\n")?;
 
     let names = names.split(',').collect();
 
     let rules = rules
         .lines()
         .map(|l| {
-            let (key, values) = l.split_once(" > ").unwrap();
+            let (key, values) = parse::split_once(input, l, " > ")?;
             let values = values
                 .split(',')
-                .map(|v| v.chars().next().unwrap())
-                .collect();
-            (key.chars().next().unwrap(), values)
+                .map(|v| parse::char(input, v))
+                .collect::<parse::Result<_>>()?;
+            Ok((parse::char(input, key)?, values))
         })
-        .fold(FxHashMap::default(), |mut rules, (key, values)| {
+        .try_fold(FxHashMap::default(), |mut rules, rule| {
+            let (key, values) = rule?;
             rules.insert(key, values);
-            rules
-        });
+            Ok(rules)
+        })?;
 
-    (names, rules)
+    Ok((names, rules))
 }
 
 fn valid_name(name: &str, rules: &FxHashMap<char, Vec<char>>) -> bool {
@@ -106,54 +108,46 @@ fn p3((names, rules): &InputPart3) -> usize {
         .sum::<usize>()
 }
 
-fn main() {
-    let now = Instant::now();
-    let input = parse_input_part1(INPUT_PART1);
-    let solution = p1(&input);
-    println!("p1 {:?} {}", now.elapsed(), solution);
-
-    let now = Instant::now();
-    let input = parse_input_part2(INPUT_PART2);
-    let solution = p2(&input);
-    println!("p2 {:?} {}", now.elapsed(), solution);
-
-    let now = Instant::now();
-    let input = parse_input_part3(INPUT_PART3);
-    let solution = p3(&input);
-    println!("p3 {:?} {}", now.elapsed(), solution);
-}
+struct Quest;
 
-const INPUT_PART2: &str = include_str!("inputs/quest07-2.txt");
-type InputPart2<'a> = InputPart1<'a>;
-fn parse_input_part2(input: &'_ str) -> InputPart2<'_> {
-    parse_input_part1(input)
-}
+impl Solution for Quest {
+    type Part1<'a> = InputPart1<'a>;
+    type Part2<'a> = InputPart2<'a>;
+    type Part3<'a> = InputPart3<'a>;
 
-const INPUT_PART3: &str = include_str!("inputs/quest07-3.txt");
-type InputPart3<'a> = InputPart1<'a>;
-fn parse_input_part3(input: &'_ str) -> InputPart3<'_> {
-    parse_input_part1(input)
-}
+    fn parse_part1(input: &str) -> anyhow::Result<Self::Part1<'_>> {
+        Ok(parse_input_part1(input)?)
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    fn parse_part2(input: &str) -> anyhow::Result<Self::Part2<'_>> {
+        Ok(parse_input_part2(input)?)
+    }
 
-    #[test]
-    fn test_p1() {
-        let input = parse_input_part1(INPUT_PART1);
-        assert_eq!(input, input);
+    fn parse_part3(input: &str) -> anyhow::Result<Self::Part3<'_>> {
+        Ok(parse_input_part3(input)?)
     }
 
-    #[test]
-    fn test_p2() {
-        let input = parse_input_part2(INPUT_PART2);
-        assert_eq!(input, input);
+    fn p1(input: &mut Self::Part1<'_>) -> impl Display {
+        p1(input)
     }
 
-    #[test]
-    fn test_p3() {
-        let input = parse_input_part3(INPUT_PART3);
-        assert_eq!(input, input);
+    fn p2(input: &mut Self::Part2<'_>) -> impl Display {
+        p2(input)
     }
+
+    fn p3(input: &mut Self::Part3<'_>) -> impl Display {
+        p3(input)
+    }
+}
+
+pub(crate) const ENTRY: Entry = Entry::new::<Quest>(crate::EVENT, 7);
+
+type InputPart2<'a> = InputPart1<'a>;
+fn parse_input_part2(input: &'_ str) -> parse::Result<InputPart2<'_>> {
+    parse_input_part1(input)
+}
+
+type InputPart3<'a> = InputPart1<'a>;
+fn parse_input_part3(input: &'_ str) -> parse::Result<InputPart3<'_>> {
+    parse_input_part1(input)
 }
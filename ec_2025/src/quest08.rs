@@ -1,18 +1,24 @@
-use std::time::Instant;
+use std::fmt::Display;
+
+use ec_common::{Entry, Solution, parse};
 
 use itertools::Itertools;
 
-const INPUT_PART1: &str = include_str!("inputs/quest08-1.txt");
 type InputPart1<'a> = Vec<(isize, isize)>;
-fn parse_input_part1(input: &'_ str) -> InputPart1<'_> {
+fn parse_input_part1(input: &'_ str) -> parse::Result<InputPart1<'_>> {
     input
         .trim()
         .split(',')
         // We do -1 here to make the math simpler?
-        .map(|v| v.parse::<isize>().unwrap() - 1)
-        .tuple_windows()
-        .map(|(l, r)| (l.min(r), r.max(l)))
-        .collect()
+        .map(|v| Ok(parse::number::<isize>(input, v)? - 1))
+        .collect::<parse::Result<Vec<_>>>()
+        .map(|nails| {
+            nails
+                .into_iter()
+                .tuple_windows()
+                .map(|(l, r)| (l.min(r), r.max(l)))
+                .collect()
+        })
 }
 
 fn p1(strings: &InputPart1, size: isize) -> usize {
@@ -60,32 +66,47 @@ fn p3(strings: &InputPart3, size: isize) -> usize {
     max
 }
 
-fn main() {
-    let now = Instant::now();
-    let input = parse_input_part1(INPUT_PART1);
-    let solution = p1(&input, 32);
-    println!("p1 {:?} {}", now.elapsed(), solution);
-
-    let now = Instant::now();
-    let input = parse_input_part2(INPUT_PART2);
-    let solution = p2(&input);
-    println!("p2 {:?} {}", now.elapsed(), solution);
-
-    let now = Instant::now();
-    let input = parse_input_part3(INPUT_PART3);
-    let solution = p3(&input, 256);
-    println!("p3 {:?} {}", now.elapsed(), solution);
+struct Quest;
+
+impl Solution for Quest {
+    type Part1<'a> = InputPart1<'a>;
+    type Part2<'a> = InputPart2<'a>;
+    type Part3<'a> = InputPart3<'a>;
+
+    fn parse_part1(input: &str) -> anyhow::Result<Self::Part1<'_>> {
+        Ok(parse_input_part1(input)?)
+    }
+
+    fn parse_part2(input: &str) -> anyhow::Result<Self::Part2<'_>> {
+        Ok(parse_input_part2(input)?)
+    }
+
+    fn parse_part3(input: &str) -> anyhow::Result<Self::Part3<'_>> {
+        Ok(parse_input_part3(input)?)
+    }
+
+    fn p1(input: &mut Self::Part1<'_>) -> impl Display {
+        p1(input, 32)
+    }
+
+    fn p2(input: &mut Self::Part2<'_>) -> impl Display {
+        p2(input)
+    }
+
+    fn p3(input: &mut Self::Part3<'_>) -> impl Display {
+        p3(input, 256)
+    }
 }
 
-const INPUT_PART2: &str = include_str!("inputs/quest08-2.txt");
+pub(crate) const ENTRY: Entry = Entry::new::<Quest>(crate::EVENT, 8);
+
 type InputPart2<'a> = InputPart1<'a>;
-fn parse_input_part2(input: &'_ str) -> InputPart2<'_> {
+fn parse_input_part2(input: &'_ str) -> parse::Result<InputPart2<'_>> {
     parse_input_part1(input)
 }
 
-const INPUT_PART3: &str = include_str!("inputs/quest08-3.txt");
 type InputPart3<'a> = InputPart1<'a>;
-fn parse_input_part3(input: &'_ str) -> InputPart3<'_> {
+fn parse_input_part3(input: &'_ str) -> parse::Result<InputPart3<'_>> {
     parse_input_part1(input)
 }
 
@@ -95,19 +116,19 @@ mod tests {
 
     #[test]
     fn test_p1() {
-        let input = parse_input_part1("1,5,2,6,8,4,1,7,3");
+        let input = parse_input_part1("1,5,2,6,8,4,1,7,3").unwrap();
         assert_eq!(p1(&input, 8), 4);
     }
 
     #[test]
     fn test_p2() {
-        let input = parse_input_part2("1,5,2,6,8,4,1,7,3,5,7,8,2");
+        let input = parse_input_part2("1,5,2,6,8,4,1,7,3,5,7,8,2").unwrap();
         assert_eq!(p2(&input), 21);
     }
 
     #[test]
     fn test_p3() {
-        let input = parse_input_part3("1,5,2,6,8,4,1,7,3,6");
+        let input = parse_input_part3("1,5,2,6,8,4,1,7,3,6").unwrap();
         assert_eq!(p3(&input, 8), 7);
         assert_eq!(input, input);
     }
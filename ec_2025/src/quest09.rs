@@ -0,0 +1,524 @@
+use std::{cmp::Reverse, fmt::Display};
+
+use ec_common::{BitGrid, Entry, ParseError, Point, Solution, UnionFind, export::Format, parse};
+
+use itertools::Itertools;
+use rayon::prelude::*;
+use serde_json::json;
+
+struct Sequence {
+    id: usize,
+    sequence: Vec<u8>,
+}
+
+impl Sequence {
+    // Parse a line like `1:CAAGCG...`.
+    fn parse(input: &str, line: &str) -> parse::Result<Self> {
+        let (id, sequence) = parse::split_once(input, line, ":")?;
+        Ok(Self {
+            id: parse::number(input, id)?,
+            sequence: sequence.bytes().collect(),
+        })
+    }
+
+    // Verify if the two given sequences could be the parents of this sequence. They "cover" all
+    // the values of this sequence.
+    fn parents(&self, l: &Sequence, r: &Sequence) -> bool {
+        l.sequence
+            .iter()
+            .zip(r.sequence.iter())
+            .zip(self.sequence.iter())
+            .all(|((p1, p2), c)| c == p1 || c == p2)
+    }
+
+    // Count the number of similar values in this sequence with the given sequence.
+    fn similarity(&self, other: &Sequence) -> usize {
+        self.sequence
+            .iter()
+            .zip(other.sequence.iter())
+            .filter(|(a, b)| a == b)
+            .count()
+    }
+
+    // Where this sequence differs from the given sequence, a bit for each symbol. A multi-word
+    // bitset, so sequences can be as long as they like.
+    fn differences(&self, other: &Sequence) -> BitGrid {
+        let mut bits = BitGrid::new(self.sequence.len(), 1);
+        for (i, (l, r)) in (0..).zip(self.sequence.iter().zip(&other.sequence)) {
+            if l != r {
+                bits.set(Point::new(i, 0));
+            }
+        }
+        bits
+    }
+}
+
+type InputPart1<'a> = Vec<Sequence>;
+fn parse_input_part1(input: &'_ str) -> parse::Result<InputPart1<'_>> {
+    let sequences = parse_sequences(input)?;
+    if sequences.len() != 3 {
+        let message = "part 1 needs exactly 3 sequences";
+        return Err(match input.lines().nth(3) {
+            Some(line) => ParseError::at(input, line, message),
+            None => ParseError::after(input, input.trim_end(), message),
+        });
+    }
+    Ok(sequences)
+}
+
+fn parse_sequences(input: &str) -> parse::Result<Vec<Sequence>> {
+    let sequences = input
+        .lines()
+        .map(|l| Sequence::parse(input, l))
+        .collect::<parse::Result<Vec<_>>>()?;
+
+    // Symbols are compared position by position, so they all have to be the same length.
+    let len = sequences.first().map_or(0, |s| s.sequence.len());
+    match input
+        .lines()
+        .zip(&sequences)
+        .find(|(_, s)| s.sequence.len() != len)
+    {
+        Some((line, s)) => Err(ParseError::at(
+            input,
+            line,
+            format!(
+                "sequence is {} long but the first is {len}",
+                s.sequence.len()
+            ),
+        )),
+        None => Ok(sequences),
+    }
+}
+
+fn p1(sequences: &InputPart1) -> usize {
+    // Only three sequences, fairly easy just to check each possibility.
+    if sequences[0].parents(&sequences[1], &sequences[2]) {
+        sequences[0].similarity(&sequences[1]) * sequences[0].similarity(&sequences[2])
+    } else if sequences[1].parents(&sequences[0], &sequences[2]) {
+        sequences[1].similarity(&sequences[0]) * sequences[1].similarity(&sequences[2])
+    } else {
+        sequences[2].similarity(&sequences[1]) * sequences[2].similarity(&sequences[0])
+    }
+}
+
+struct Relationship {
+    child: usize,
+    p1: usize,
+    p2: usize,
+    sim_p1: usize,
+    sim_p2: usize,
+}
+
+// The goal here is to find relationships (still N^3) but to minimize the effort while finding
+// those relationships. Calling parents() on pairs made it take a few seconds to run. If we do the
+// work of calculation the differences of each pair (N^2), then the N^3 loop is more efficient. At
+// least for my case.
+fn find_relationships(sequences: &[Sequence]) -> Vec<Relationship> {
+    let n = sequences.len();
+    let len = sequences.first().map_or(0, |s| s.sequence.len());
+
+    let mut differences = vec![vec![BitGrid::new(len, 1); n]; n];
+    for i in 0..n {
+        for j in i + 1..n {
+            let bits = sequences[i].differences(&sequences[j]);
+            differences[j][i] = bits.clone();
+            differences[i][j] = bits;
+        }
+    }
+
+    // Now we can test for lineage by doing some bitwise logic.
+    (0..n)
+        .into_par_iter()
+        .flat_map(|p1| {
+            let mut found_relationships = Vec::new();
+            for p2 in (p1 + 1)..n {
+                for (c, differs) in differences.iter().enumerate() {
+                    if c == p1 || c == p2 {
+                        continue;
+                    }
+                    // Every symbol that differs from p1 has to match p2.
+                    if differs[p1].is_disjoint(&differs[p2]) {
+                        found_relationships.push(Relationship {
+                            child: c,
+                            p1,
+                            p2,
+                            sim_p1: len - differs[p1].count(),
+                            sim_p2: len - differs[p2].count(),
+                        });
+                    }
+                }
+            }
+            found_relationships
+        })
+        .collect()
+}
+
+// Everyone's parents, as found by `find_relationships`.
+struct FamilyTree {
+    sequences: Vec<Sequence>,
+    // Every pair that could be the parents of each sequence, the one we went with first.
+    candidates: Vec<Vec<Relationship>>,
+}
+
+impl FamilyTree {
+    // When a child has a choice of parents we go with the pair most like them (the highest
+    // product of similarities), and then the pair that comes first in the input.
+    fn new(sequences: Vec<Sequence>) -> Self {
+        let mut candidates = sequences.iter().map(|_| vec![]).collect::<Vec<_>>();
+        for r in find_relationships(&sequences) {
+            candidates[r.child].push(r);
+        }
+        for c in &mut candidates {
+            c.sort_by_key(|r| (Reverse(r.sim_p1 * r.sim_p2), r.p1, r.p2));
+        }
+        Self {
+            sequences,
+            candidates,
+        }
+    }
+
+    // The parents we went with for each child.
+    fn relationships(&self) -> impl Iterator<Item = &Relationship> {
+        self.candidates.iter().filter_map(|c| c.first())
+    }
+
+    // The candidates for each child that has more than one pair to choose from.
+    fn ambiguous(&self) -> impl Iterator<Item = &[Relationship]> {
+        self.candidates
+            .iter()
+            .filter(|c| c.len() > 1)
+            .map(Vec::as_slice)
+    }
+
+    // A child and their candidates like `5 -> 1 + 2 or 3 + 4`, using the ids from the input.
+    fn describe(&self, candidates: &[Relationship]) -> String {
+        let id = |i: usize| self.sequences[i].id;
+        let pairs = candidates
+            .iter()
+            .map(|r| format!("{} + {}", id(r.p1), id(r.p2)))
+            .join(" or ");
+        format!("{} -> {pairs}", id(candidates[0].child))
+    }
+
+    // Each family is everyone connected by being a parent or child.
+    fn families(&self) -> UnionFind {
+        let mut families = UnionFind::new(self.sequences.len());
+        for r in self.relationships() {
+            families.union(r.child, r.p1);
+            families.union(r.child, r.p2);
+        }
+        families
+    }
+
+    // Which family each sequence is in, numbered in order of their first sequence.
+    fn family_of(&self) -> Vec<usize> {
+        let mut family_of = vec![0; self.sequences.len()];
+        for (family, members) in self.families().components().into_iter().enumerate() {
+            for i in members {
+                family_of[i] = family;
+            }
+        }
+        family_of
+    }
+
+    // A Graphviz graph with an edge from each parent to their child, filled in by family so it's
+    // easy to spot a relationship that joins two families that shouldn't be. The parents we
+    // didn't go with for an ambiguous child are dashed.
+    fn dot(&self) -> String {
+        let id = |i: usize| self.sequences[i].id;
+        let mut dot =
+            String::from("digraph family {\n    node [style=filled colorscheme=set312];\n");
+        for (i, family) in self.family_of().into_iter().enumerate() {
+            dot += &format!("    {} [fillcolor={}];\n", id(i), family % 12 + 1);
+        }
+        for candidates in &self.candidates {
+            for (n, r) in candidates.iter().enumerate() {
+                let style = if n == 0 { "" } else { " [style=dashed]" };
+                dot += &format!("    {} -> {}{style};\n", id(r.p1), id(r.child));
+                dot += &format!("    {} -> {}{style};\n", id(r.p2), id(r.child));
+            }
+        }
+        dot + "}\n"
+    }
+
+    // Every sequence with its family, children and the pairs that could be its parents (the one
+    // we went with first), in the order of the input.
+    fn json(&self) -> serde_json::Value {
+        let id = |i: usize| self.sequences[i].id;
+        let mut children = vec![vec![]; self.sequences.len()];
+        for r in self.relationships() {
+            children[r.p1].push(id(r.child));
+            children[r.p2].push(id(r.child));
+        }
+        for c in &mut children {
+            c.sort();
+        }
+
+        let family_of = self.family_of();
+        (0..self.sequences.len())
+            .map(|i| {
+                let parents = self.candidates[i]
+                    .iter()
+                    .map(|r| [id(r.p1), id(r.p2)])
+                    .collect::<Vec<_>>();
+                json!({
+                    "id": id(i),
+                    "family": family_of[i],
+                    "parents": parents,
+                    "children": children[i],
+                })
+            })
+            .collect()
+    }
+}
+
+// A line for each child like `5 -> 1 + 2`, with every pair they could have when there's a choice.
+impl Display for FamilyTree {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for candidates in self.candidates.iter().filter(|c| !c.is_empty()) {
+            writeln!(f, "{}", self.describe(candidates))?;
+        }
+        Ok(())
+    }
+}
+
+// Part 1's three sequences are a family tree too, just a small one.
+fn family_tree(part: u8, input: &str) -> parse::Result<FamilyTree> {
+    let sequences = match part {
+        1 => parse_input_part1(input)?,
+        2 => parse_input_part2(input)?,
+        _ => parse_input_part3(input)?,
+    };
+    Ok(FamilyTree::new(sequences))
+}
+
+fn p2(tree: &FamilyTree) -> usize {
+    tree.relationships().map(|r| r.sim_p1 * r.sim_p2).sum()
+}
+
+fn p3(tree: &FamilyTree) -> usize {
+    // We want to find the largest family and return it's value (sum of ids).
+    tree.families()
+        .largest()
+        .into_iter()
+        .map(|i| tree.sequences[i].id)
+        .sum()
+}
+
+struct Quest;
+
+impl Solution for Quest {
+    type Part1<'a> = InputPart1<'a>;
+    type Part2<'a> = FamilyTree;
+    type Part3<'a> = FamilyTree;
+
+    fn parse_part1(input: &str) -> anyhow::Result<Self::Part1<'_>> {
+        Ok(parse_input_part1(input)?)
+    }
+
+    fn parse_part2(input: &str) -> anyhow::Result<Self::Part2<'_>> {
+        Ok(family_tree(2, input)?)
+    }
+
+    fn parse_part3(input: &str) -> anyhow::Result<Self::Part3<'_>> {
+        Ok(family_tree(3, input)?)
+    }
+
+    fn p1(input: &mut Self::Part1<'_>) -> impl Display {
+        p1(input)
+    }
+
+    fn p2(input: &mut Self::Part2<'_>) -> impl Display {
+        p2(input)
+    }
+
+    fn p3(input: &mut Self::Part3<'_>) -> impl Display {
+        p3(input)
+    }
+
+    fn export(part: u8, input: &str, format: Format) -> anyhow::Result<Option<String>> {
+        let tree = family_tree(part, input)?;
+        Ok(Some(match format {
+            Format::Dot => tree.dot(),
+            Format::Json => serde_json::to_string_pretty(&tree.json())?,
+        }))
+    }
+
+    fn guesses(part: u8, input: &str) -> anyhow::Result<Vec<String>> {
+        let tree = family_tree(part, input)?;
+        Ok(tree.ambiguous().map(|c| tree.describe(c)).collect())
+    }
+}
+
+pub(crate) const ENTRY: Entry = Entry::new::<Quest>(crate::EVENT, 9);
+
+type InputPart2<'a> = InputPart1<'a>;
+fn parse_input_part2(input: &'_ str) -> parse::Result<InputPart2<'_>> {
+    parse_sequences(input)
+}
+
+type InputPart3<'a> = InputPart1<'a>;
+fn parse_input_part3(input: &'_ str) -> parse::Result<InputPart3<'_>> {
+    parse_sequences(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_p1() {
+        let input = parse_input_part1(
+            "1:CAAGCGCTAAGTTCGCTGGATGTGTGCCCGCG
+2:CTTGAATTGGGCCGTTTACCTGGTTTAACCAT
+3:CTAGCGCTGAGCTGGCTGCCTGGTTGACCGCG",
+        )
+        .unwrap();
+        assert_eq!(p1(&input), 414);
+
+        let error = parse_input_part1("1:AC\n2:CA").map(|_| ()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "part 1 needs exactly 3 sequences at line 2 col 5"
+        );
+    }
+
+    #[test]
+    fn test_p2() {
+        let input = parse_input_part2(
+            "1:GCAGGCGAGTATGATACCCGGCTAGCCACCCC
+2:TCTCGCGAGGATATTACTGGGCCAGACCCCCC
+3:GGTGGAACATTCGAAAGTTGCATAGGGTGGTG
+4:GCTCGCGAGTATATTACCGAACCAGCCCCTCA
+5:GCAGCTTAGTATGACCGCCAAATCGCGACTCA
+6:AGTGGAACCTTGGATAGTCTCATATAGCGGCA
+7:GGCGTAATAATCGGATGCTGCAGAGGCTGCTG",
+        )
+        .unwrap();
+        assert_eq!(p2(&FamilyTree::new(input)), 1245);
+    }
+
+    #[test]
+    fn test_p3() {
+        let input = parse_input_part3(
+            "1:GCAGGCGAGTATGATACCCGGCTAGCCACCCC
+2:TCTCGCGAGGATATTACTGGGCCAGACCCCCC
+3:GGTGGAACATTCGAAAGTTGCATAGGGTGGTG
+4:GCTCGCGAGTATATTACCGAACCAGCCCCTCA
+5:GCAGCTTAGTATGACCGCCAAATCGCGACTCA
+6:AGTGGAACCTTGGATAGTCTCATATAGCGGCA
+7:GGCGTAATAATCGGATGCTGCAGAGGCTGCTG",
+        )
+        .unwrap();
+        assert_eq!(p3(&FamilyTree::new(input)), 12);
+
+        let input = parse_input_part3(
+            "1:GCAGGCGAGTATGATACCCGGCTAGCCACCCC
+2:TCTCGCGAGGATATTACTGGGCCAGACCCCCC
+3:GGTGGAACATTCGAAAGTTGCATAGGGTGGTG
+4:GCTCGCGAGTATATTACCGAACCAGCCCCTCA
+5:GCAGCTTAGTATGACCGCCAAATCGCGACTCA
+6:AGTGGAACCTTGGATAGTCTCATATAGCGGCA
+7:GGCGTAATAATCGGATGCTGCAGAGGCTGCTG
+8:GGCGTAAAGTATGGATGCTGGCTAGGCACCCG",
+        )
+        .unwrap();
+        assert_eq!(p3(&FamilyTree::new(input)), 36);
+    }
+
+    #[test]
+    fn test_family_tree() {
+        let input = parse_input_part2(
+            "1:GCAGGCGAGTATGATACCCGGCTAGCCACCCC
+2:TCTCGCGAGGATATTACTGGGCCAGACCCCCC
+3:GGTGGAACATTCGAAAGTTGCATAGGGTGGTG
+4:GCTCGCGAGTATATTACCGAACCAGCCCCTCA
+5:GCAGCTTAGTATGACCGCCAAATCGCGACTCA
+6:AGTGGAACCTTGGATAGTCTCATATAGCGGCA
+7:GGCGTAATAATCGGATGCTGCAGAGGCTGCTG",
+        )
+        .unwrap();
+        assert_eq!(
+            FamilyTree::new(input).to_string(),
+            "1 -> 2 + 5\n3 -> 6 + 7\n4 -> 2 + 5\n"
+        );
+    }
+
+    #[test]
+    fn test_export() {
+        let input = "1:GCAGGCGAGTATGATACCCGGCTAGCCACCCC
+2:TCTCGCGAGGATATTACTGGGCCAGACCCCCC
+3:GGTGGAACATTCGAAAGTTGCATAGGGTGGTG
+4:GCTCGCGAGTATATTACCGAACCAGCCCCTCA
+5:GCAGCTTAGTATGACCGCCAAATCGCGACTCA
+6:AGTGGAACCTTGGATAGTCTCATATAGCGGCA
+7:GGCGTAATAATCGGATGCTGCAGAGGCTGCTG";
+
+        let dot = ENTRY.export(2, input, Format::Dot).unwrap().unwrap();
+        assert!(dot.starts_with("digraph family {"));
+        for line in [
+            "1 [fillcolor=1];",
+            "3 [fillcolor=2];",
+            "2 -> 1;",
+            "5 -> 4;",
+            "7 -> 3;",
+        ] {
+            assert!(dot.contains(line), "{line} is missing from\n{dot}");
+        }
+
+        let json = ENTRY.export(3, input, Format::Json).unwrap().unwrap();
+        let json: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            json[0],
+            json!({"id": 1, "family": 0, "parents": [[2, 5]], "children": []})
+        );
+        assert_eq!(
+            json[1],
+            json!({"id": 2, "family": 0, "parents": [], "children": [1, 4]})
+        );
+        assert_eq!(json[6]["family"], 1);
+    }
+
+    #[test]
+    fn test_ambiguous() {
+        // 1 + 2 and 1 + 4 could both be the parents of 3, and are just as much like it.
+        let input = "1:AAAA\n2:CCCC\n3:AACC\n4:GGCC";
+        let tree = FamilyTree::new(parse_input_part2(input).unwrap());
+        assert_eq!(tree.to_string(), "3 -> 1 + 2 or 1 + 4\n");
+        assert_eq!(
+            tree.relationships()
+                .map(|r| (r.p1, r.p2))
+                .collect::<Vec<_>>(),
+            [(0, 1)]
+        );
+        assert_eq!(tree.families().largest(), [0, 1, 2]);
+
+        assert_eq!(ENTRY.guesses(2, input).unwrap(), ["3 -> 1 + 2 or 1 + 4"]);
+
+        // The one more like the child wins.
+        let input = parse_input_part2("1:AAAA\n2:CCCC\n3:AAAC\n4:GGAC").unwrap();
+        let tree = FamilyTree::new(input);
+        assert_eq!(tree.to_string(), "3 -> 1 + 4 or 1 + 2\n");
+        let dot = tree.dot();
+        assert!(dot.contains("    4 -> 3;\n"));
+        assert!(dot.contains("    2 -> 3 [style=dashed];\n"));
+    }
+
+    #[test]
+    fn test_long_sequences() {
+        // Way past what fits in a u128. The child takes the first half from 1 and the rest from 2.
+        let (a, b) = ("A".repeat(300), "C".repeat(300));
+        let child = format!("{}{}", &a[..150], &b[..150]);
+        let input = parse_input_part2(&format!("1:{a}\n2:{b}\n3:{child}")).unwrap();
+        let tree = FamilyTree::new(input);
+        assert_eq!(tree.to_string(), "3 -> 1 + 2\n");
+        assert_eq!(p2(&tree), 150 * 150);
+
+        let err = parse_input_part2("1:ACGT\n2:ACG").map(|_| ()).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("sequence is 3 long but the first is 4")
+        );
+    }
+}
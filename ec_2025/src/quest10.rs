@@ -1,43 +1,43 @@
-use std::{collections::VecDeque, ops::Add, time::Instant};
+use std::{fmt::Display, ops::Add};
+
+use ec_common::{
+    Entry, Grid, ParseError, Point, Solution, parse,
+    search::{Timed, bfs_timed},
+};
 
 use rustc_hash::{FxHashMap, FxHashSet};
 
 // We just collect all the information from the input that we'll need to solve the puzzles.
-const INPUT_PART1: &str = include_str!("inputs/quest10-1.txt");
 type InputPart1<'a> = (Square, Vec<Square>, FxHashSet<Square>, isize, isize);
-fn parse_input_part1(input: &'_ str) -> InputPart1<'_> {
-    let input = input
-        .lines()
-        .map(|l| l.chars().collect())
-        .collect::<Vec<Vec<char>>>();
+fn parse_input_part1(input: &'_ str) -> parse::Result<InputPart1<'_>> {
+    let board = Grid::try_parse_with(input, |c| "DS#.".contains(c).then_some(c))?;
+    let Some(dragon) = board.find(|&c| c == 'D') else {
+        return Err(ParseError::at(
+            input,
+            input.trim(),
+            "there's no dragon on the board",
+        ));
+    };
 
-    let mut dragon = Square::new(0, 0);
+    let square = |p: Point| Square::new(p.x as usize, p.y as usize);
     let mut sheep = vec![];
     let mut hideouts = FxHashSet::default();
-
-    for (row, line) in input.iter().enumerate() {
-        for (column, c) in line.iter().enumerate() {
-            match c {
-                'D' => {
-                    dragon = Square::new(column, row);
-                }
-                '#' => {
-                    hideouts.insert(Square::new(column, row));
-                }
-                'S' => {
-                    sheep.push(Square::new(column, row));
-                }
-                _ => (),
+    for (p, c) in board.iter() {
+        match c {
+            '#' => {
+                hideouts.insert(square(p));
             }
+            'S' => sheep.push(square(p)),
+            _ => (),
         }
     }
-    (
-        dragon,
+    Ok((
+        square(dragon),
         sheep,
         hideouts,
-        input.len() as isize,
-        input[0].len() as isize,
-    )
+        board.height() as isize,
+        board.width() as isize,
+    ))
 }
 
 #[derive(Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Clone)]
@@ -103,35 +103,19 @@ impl Add<&Square> for &Square {
     }
 }
 
-// We can just BFS all the moves.
+// We can just BFS the moves and count the sheep the dragon lands on along the way.
 fn p1((dragon, sheep, _, rows, columns): &InputPart1, moves: usize) -> usize {
-    // Maintain the list of work to do and previously seen Squares.
-    let mut frontier = VecDeque::new();
-    frontier.push_back((*dragon, 0));
-    let mut seen = FxHashSet::default();
-    seen.insert(*dragon);
-
-    let mut total = 0;
-    while let Some((point, steps)) = frontier.pop_front() {
-        // If we have a sheep, we can mark it.
-        if sheep.contains(&point) {
-            total += 1;
-        }
-
-        // If we've exhausted our steps, we'll stop this path.
-        if steps == moves {
-            continue;
-        }
-
-        // Add all of our next moves that we haven't been to before.
-        for neighbor in point.neighbors(*columns, *rows) {
-            if seen.insert(neighbor) {
-                frontier.push_back((neighbor, steps + 1));
-            }
-        }
-    }
-
-    total
+    let search = bfs_timed(
+        [*dragon],
+        |square, _| square.neighbors(*columns, *rows),
+        moves,
+        |_| false,
+    );
+    let reached = search
+        .distances()
+        .map(|(timed, _)| timed.node)
+        .collect::<FxHashSet<_>>();
+    sheep.iter().filter(|sheep| reached.contains(sheep)).count()
 }
 
 // The goal here is to mark all the places the dragon can go and then move the sheep around and see
@@ -139,20 +123,13 @@ fn p1((dragon, sheep, _, rows, columns): &InputPart1, moves: usize) -> usize {
 // start graph but it was too slow for the puzzle input (worked on examples).
 fn p2((dragon, sheep, hideouts, rows, columns): &InputPart2, moves: usize) -> usize {
     // Find all the places the dragon can be at a given step.
-    let mut frontier = VecDeque::new();
-    frontier.push_back((*dragon, 0));
-    let mut dragons = FxHashSet::default();
-    dragons.insert((*dragon, 0));
-    while let Some((point, steps)) = frontier.pop_front() {
-        if steps == moves {
-            continue;
-        }
-        for neighbor in point.neighbors(*columns, *rows) {
-            if dragons.insert((neighbor, steps + 1)) {
-                frontier.push_back((neighbor, steps + 1));
-            }
-        }
-    }
+    let dragons = bfs_timed(
+        [*dragon],
+        |square, _| square.neighbors(*columns, *rows),
+        moves,
+        |_| false,
+    );
+    let dragon_at = |node, time| dragons.contains(&Timed { node, time });
 
     // Iterate through all the moves the sheep can make over all the moves and see if it would
     // collide with a dragon where there isn't a hideout.
@@ -161,8 +138,7 @@ fn p2((dragon, sheep, hideouts, rows, columns): &InputPart2, moves: usize) -> us
     for step in 0..=moves {
         sheep.retain(|sheep| {
             let p = *sheep + Square::new(0, step);
-            hideouts.contains(&p)
-                || (!dragons.contains(&(p, step)) && !dragons.contains(&(p, step + 1)))
+            hideouts.contains(&p) || (!dragon_at(p, step) && !dragon_at(p, step + 1))
             // Note we are checking this step and the next step because the sheep could walk
             // into the dragon here.
         });
@@ -315,32 +291,47 @@ fn p3((dragon, sheep, hideouts, rows, columns): &InputPart3) -> usize {
     board.dfs(state)
 }
 
-fn main() {
-    let now = Instant::now();
-    let input = parse_input_part1(INPUT_PART1);
-    let solution = p1(&input, 4);
-    println!("p1 {:?} {}", now.elapsed(), solution);
-
-    let now = Instant::now();
-    let input = parse_input_part2(INPUT_PART2);
-    let solution = p2(&input, 20);
-    println!("p2 {:?} {}", now.elapsed(), solution);
-
-    let now = Instant::now();
-    let input = parse_input_part3(INPUT_PART3);
-    let solution = p3(&input);
-    println!("p3 {:?} {}", now.elapsed(), solution);
+struct Quest;
+
+impl Solution for Quest {
+    type Part1<'a> = InputPart1<'a>;
+    type Part2<'a> = InputPart2<'a>;
+    type Part3<'a> = InputPart3<'a>;
+
+    fn parse_part1(input: &str) -> anyhow::Result<Self::Part1<'_>> {
+        Ok(parse_input_part1(input)?)
+    }
+
+    fn parse_part2(input: &str) -> anyhow::Result<Self::Part2<'_>> {
+        Ok(parse_input_part2(input)?)
+    }
+
+    fn parse_part3(input: &str) -> anyhow::Result<Self::Part3<'_>> {
+        Ok(parse_input_part3(input)?)
+    }
+
+    fn p1(input: &mut Self::Part1<'_>) -> impl Display {
+        p1(input, 4)
+    }
+
+    fn p2(input: &mut Self::Part2<'_>) -> impl Display {
+        p2(input, 20)
+    }
+
+    fn p3(input: &mut Self::Part3<'_>) -> impl Display {
+        p3(input)
+    }
 }
 
-const INPUT_PART2: &str = include_str!("inputs/quest10-2.txt");
+pub(crate) const ENTRY: Entry = Entry::new::<Quest>(crate::EVENT, 10);
+
 type InputPart2<'a> = InputPart1<'a>;
-fn parse_input_part2(input: &'_ str) -> InputPart2<'_> {
+fn parse_input_part2(input: &'_ str) -> parse::Result<InputPart2<'_>> {
     parse_input_part1(input)
 }
 
-const INPUT_PART3: &str = include_str!("inputs/quest10-3.txt");
 type InputPart3<'a> = InputPart1<'a>;
-fn parse_input_part3(input: &'_ str) -> InputPart3<'_> {
+fn parse_input_part3(input: &'_ str) -> parse::Result<InputPart3<'_>> {
     parse_input_part1(input)
 }
 
@@ -364,7 +355,8 @@ S.S..S..S....
 .........S...
 .......S....S
 SS.....S..S..",
-        );
+        )
+        .unwrap();
         assert_eq!(p1(&input, 3), 27);
     }
 
@@ -384,32 +376,33 @@ S.S..S..S###.
 ..#.##...S##.
 .#...#.S#...S
 SS...#.S.#S..",
-        );
+        )
+        .unwrap();
         assert_eq!(p2(&input, 3), 27);
     }
 
     #[test]
     fn test_p3() {
-        let input = parse_input_part3("SSS\n..#\n#.#\n#D.");
+        let input = parse_input_part3("SSS\n..#\n#.#\n#D.").unwrap();
         assert_eq!(p3(&input), 15);
     }
 
     #[test]
     fn test_p3_2() {
-        let input = parse_input_part3("SSS\n..#\n..#\n.##\n.D#");
+        let input = parse_input_part3("SSS\n..#\n..#\n.##\n.D#").unwrap();
         assert_eq!(p3(&input), 8);
     }
 
     #[test]
     fn test_p3_3() {
         // I had a crazy bug here where I had pasted the example with spaces in-front and behind.
-        let input = parse_input_part3("..S..\n.....\n..#..\n.....\n..D..");
+        let input = parse_input_part3("..S..\n.....\n..#..\n.....\n..D..").unwrap();
         assert_eq!(p3(&input), 44);
     }
 
     #[test]
     fn test_p3_4() {
-        let input = parse_input_part3(".SS.S\n#...#\n...#.\n##..#\n.####\n##D.#");
+        let input = parse_input_part3(".SS.S\n#...#\n...#.\n##..#\n.####\n##D.#").unwrap();
         assert_eq!(p3(&input), 4406);
     }
 }
@@ -0,0 +1,191 @@
+use std::fmt::Display;
+
+use ec_common::{Entry, Simulation, Solution, Stepper, parse};
+
+type InputPart1<'a> = Vec<usize>;
+fn parse_input_part1(input: &'_ str) -> parse::Result<InputPart1<'_>> {
+    input.lines().map(|l| parse::number(input, l)).collect()
+}
+
+// The ducks in each column, moving right until they can't and then left until they're even.
+#[derive(Debug, Clone)]
+struct Flock {
+    columns: Vec<usize>,
+    moving_right: bool,
+}
+
+impl Flock {
+    fn new(columns: &[usize]) -> Self {
+        Self {
+            columns: columns.to_vec(),
+            moving_right: true,
+        }
+    }
+
+    // Move a duck between each pair of columns that fits the phase. Returns whether any moved.
+    fn shift(&mut self) -> bool {
+        let mut moved = false;
+        for i in 0..self.columns.len() - 1 {
+            let (a, b) = (self.columns[i], self.columns[i + 1]);
+            if (self.moving_right && a > b) || (!self.moving_right && a < b) {
+                moved = true;
+                self.columns[i] = if self.moving_right { a - 1 } else { a + 1 };
+                self.columns[i + 1] = if self.moving_right { b + 1 } else { b - 1 };
+            }
+        }
+        moved
+    }
+
+    fn checksum(&self) -> usize {
+        (1..).zip(self.columns.iter()).map(|(i, n)| i * n).sum()
+    }
+}
+
+// A round that doesn't move anything right switches to moving left within the same round.
+impl Simulation for Flock {
+    fn state(&self) -> String {
+        self.columns
+            .iter()
+            .map(|&n| format!("{n:>4} {}", "#".repeat(n.min(80))))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn step(&mut self) -> bool {
+        if self.moving_right && self.shift() {
+            return true;
+        }
+        self.moving_right = false;
+        self.shift()
+    }
+
+    fn status(&self) -> String {
+        let phase = match self.moving_right {
+            true => "phase 1 (moving right)",
+            false => "phase 2 (moving left)",
+        };
+        format!("{phase}, checksum {}", self.checksum())
+    }
+}
+
+// For p1, simply run the simulation as described.
+fn p1(input: &InputPart1) -> usize {
+    let mut flock = Flock::new(input);
+    for _ in 0..10 {
+        if !flock.step() {
+            break;
+        }
+    }
+    flock.checksum()
+}
+
+// For p2, do the same but run until it's equalized.
+fn p2(input: &InputPart2) -> usize {
+    let mut flock = Flock::new(input);
+
+    // Shift right phase.
+    let mut round = 0;
+    while flock.shift() {
+        round += 1;
+    }
+
+    // Shift left phase (we can calculate?)
+    let input = &flock.columns;
+    let mean = input.iter().sum::<usize>() / input.len();
+    round
+        + input
+            .iter()
+            .filter(|&&i| i < mean)
+            .map(|i| mean - i)
+            .sum::<usize>()
+}
+
+// p3 wasn't gonna finish (my input produced 133_914_234_649_730). We have to find some other solution. The input is sorted (unlike examples). We can ignore phase 1. LOL. We just have to figure out how many rounds will it take to fill in the gaps. Get either of the values below the mean or above the mean and sum them (should be same).
+fn p3(input: &InputPart3) -> usize {
+    let mean = input.iter().sum::<usize>() / input.len();
+    input.iter().filter(|&&i| i < mean).map(|i| mean - i).sum()
+}
+
+struct Quest;
+
+impl Solution for Quest {
+    type Part1<'a> = InputPart1<'a>;
+    type Part2<'a> = InputPart2<'a>;
+    type Part3<'a> = InputPart3<'a>;
+
+    fn parse_part1(input: &str) -> anyhow::Result<Self::Part1<'_>> {
+        Ok(parse_input_part1(input)?)
+    }
+
+    fn parse_part2(input: &str) -> anyhow::Result<Self::Part2<'_>> {
+        Ok(parse_input_part2(input)?)
+    }
+
+    fn parse_part3(input: &str) -> anyhow::Result<Self::Part3<'_>> {
+        Ok(parse_input_part3(input)?)
+    }
+
+    fn p1(input: &mut Self::Part1<'_>) -> impl Display {
+        p1(input)
+    }
+
+    fn p2(input: &mut Self::Part2<'_>) -> impl Display {
+        p2(input)
+    }
+
+    fn p3(input: &mut Self::Part3<'_>) -> impl Display {
+        p3(input)
+    }
+
+    fn simulate(_part: u8, input: &str) -> anyhow::Result<Option<Stepper>> {
+        Ok(Some(Stepper::new(Flock::new(&parse_input_part1(input)?))))
+    }
+}
+
+pub(crate) const ENTRY: Entry = Entry::new::<Quest>(crate::EVENT, 11);
+
+type InputPart2<'a> = InputPart1<'a>;
+fn parse_input_part2(input: &'_ str) -> parse::Result<InputPart2<'_>> {
+    parse_input_part1(input)
+}
+
+type InputPart3<'a> = InputPart1<'a>;
+fn parse_input_part3(input: &'_ str) -> parse::Result<InputPart3<'_>> {
+    parse_input_part1(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_p1() {
+        let input = "9\n1\n1\n4\n9\n6";
+        let input = parse_input_part1(input).unwrap();
+        assert_eq!(p1(&input), 109);
+    }
+
+    #[test]
+    fn test_simulate() {
+        // Stepping until it's balanced takes as many rounds as p2 counts.
+        let input = parse_input_part1("9\n1\n1\n4\n9\n6").unwrap();
+        let mut stepper = Stepper::new(Flock::new(&input));
+        assert_eq!(stepper.seek(usize::MAX), 11);
+        assert_eq!(stepper.state().lines().next(), Some("   5 #####"));
+        assert_eq!(stepper.status(), "phase 2 (moving left), checksum 105");
+    }
+
+    #[test]
+    fn test_p2() {
+        let input = "9\n1\n1\n4\n9\n6";
+        let input = parse_input_part2(input).unwrap();
+        assert_eq!(p2(&input), 11);
+    }
+
+    #[test]
+    fn test_p3() {
+        let input = "3\n4\n4\n4\n7\n8";
+        let input = parse_input_part3(input).unwrap();
+        assert_eq!(p3(&input), 5);
+    }
+}
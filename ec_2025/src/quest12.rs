@@ -0,0 +1,153 @@
+use std::{collections::VecDeque, fmt::Display};
+
+use ec_common::{BitGrid, Entry, Grid, ParseError, Point, Solution, parse};
+
+use itertools::Itertools;
+use rayon::iter::{IntoParallelRefMutIterator, ParallelBridge, ParallelIterator};
+
+type InputPart1<'a> = Vec<Vec<usize>>;
+fn parse_input_part1(input: &'_ str) -> parse::Result<InputPart1<'_>> {
+    let grid = Grid::try_parse_with(input, |c| c.to_digit(10).map(|d| d as usize))?;
+    if grid.width() == 0 {
+        return Err(ParseError::after(input, input, "there are no barrels"));
+    }
+    Ok(grid.rows().map(<[usize]>::to_vec).collect())
+}
+
+const NEIGHBORS: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+// Do a BFS to find all neighboring barrels that would explode if the given barrels are ignited.
+// Using a BitGrid to track barrels was faster than FxHashSet and Vec<bool>.
+fn bfs(input: &InputPart3, barrels: &[(isize, isize)]) -> BitGrid {
+    let (max_row, max_col) = (input.len(), input[0].len());
+    let mut seen = BitGrid::new(max_col, max_row);
+
+    let mut frontier = VecDeque::with_capacity(max_row * max_col / 4);
+    for &(r, c) in barrels {
+        seen.set(Point::new(c, r));
+        frontier.push_back((r, c));
+    }
+
+    while let Some((r, c)) = frontier.pop_front() {
+        for (dr, dc) in NEIGHBORS.iter() {
+            let (nr, nc) = (r + dr, c + dc);
+            if nr >= 0
+                && nr < max_row as isize
+                && nc >= 0
+                && nc < max_col as isize
+                && input[nr as usize][nc as usize] <= input[r as usize][c as usize]
+                && seen.set(Point::new(nc, nr))
+            {
+                frontier.push_back((nr, nc));
+            }
+        }
+    }
+    seen
+}
+
+fn p1(input: &InputPart1) -> usize {
+    bfs(input, &[(0, 0)]).count()
+}
+
+fn p2(input: &InputPart2) -> usize {
+    bfs(
+        input,
+        &[
+            (0, 0),
+            ((input.len() - 1) as isize, (input[0].len() - 1) as isize),
+        ],
+    )
+    .count()
+}
+
+fn p3(input: &InputPart3) -> usize {
+    // BFS all positions in parallel
+    let mut results: Vec<BitGrid> = (0..input.len() as isize)
+        .cartesian_product(0..input[0].len() as isize)
+        .par_bridge()
+        .map(|(r, c)| bfs(input, &[(r, c)]))
+        .collect();
+
+    // Instead of sorting O(n log n), we can just max_by_key O(n)
+    // We then pull it out using swap_remove.
+    // We can do this for the second as well
+
+    // Find largest
+    let largest_index = results
+        .iter()
+        .enumerate()
+        .max_by_key(|(_, m)| m.count())
+        .unwrap()
+        .0;
+    let largest = results.swap_remove(largest_index);
+    let first_size = largest.count();
+
+    // Remove overlap from all remaining (par_iter_mut).
+    results.par_iter_mut().for_each(|mask| {
+        *mask -= &largest;
+    });
+
+    // Find second largest
+    let second_index = results
+        .iter()
+        .enumerate()
+        .max_by_key(|(_, m)| m.count())
+        .unwrap()
+        .0;
+    let second = results.swap_remove(second_index);
+    let second_size = second.count();
+
+    // Remove overlap from remaining
+    results.par_iter_mut().for_each(|mask| {
+        *mask -= &second;
+    });
+
+    // Find third largest
+    let third_size = results.iter().map(|m| m.count()).max().unwrap();
+
+    first_size + second_size + third_size
+}
+
+struct Quest;
+
+impl Solution for Quest {
+    type Part1<'a> = InputPart1<'a>;
+    type Part2<'a> = InputPart2<'a>;
+    type Part3<'a> = InputPart3<'a>;
+
+    fn parse_part1(input: &str) -> anyhow::Result<Self::Part1<'_>> {
+        Ok(parse_input_part1(input)?)
+    }
+
+    fn parse_part2(input: &str) -> anyhow::Result<Self::Part2<'_>> {
+        Ok(parse_input_part2(input)?)
+    }
+
+    fn parse_part3(input: &str) -> anyhow::Result<Self::Part3<'_>> {
+        Ok(parse_input_part3(input)?)
+    }
+
+    fn p1(input: &mut Self::Part1<'_>) -> impl Display {
+        p1(input)
+    }
+
+    fn p2(input: &mut Self::Part2<'_>) -> impl Display {
+        p2(input)
+    }
+
+    fn p3(input: &mut Self::Part3<'_>) -> impl Display {
+        p3(input)
+    }
+}
+
+pub(crate) const ENTRY: Entry = Entry::new::<Quest>(crate::EVENT, 12);
+
+type InputPart2<'a> = InputPart1<'a>;
+fn parse_input_part2(input: &'_ str) -> parse::Result<InputPart2<'_>> {
+    parse_input_part1(input)
+}
+
+type InputPart3<'a> = InputPart1<'a>;
+fn parse_input_part3(input: &'_ str) -> parse::Result<InputPart3<'_>> {
+    parse_input_part1(input)
+}
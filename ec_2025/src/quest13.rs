@@ -1,13 +1,14 @@
-use std::time::Instant;
+use std::fmt::Display;
+
+use ec_common::{Entry, Solution, parse};
 
-const INPUT_PART1: &str = include_str!("inputs/quest13-1.txt");
 type InputPart1<'a> = Vec<usize>;
-fn parse_input_part1(input: &'_ str) -> InputPart1<'_> {
+fn parse_input_part1(input: &'_ str) -> parse::Result<InputPart1<'_>> {
     // We just get the lines as usize.
     input
         .trim()
         .lines()
-        .map(|l| l.parse::<usize>().unwrap())
+        .map(|l| parse::number(input, l))
         .collect()
 }
 
@@ -23,17 +24,15 @@ fn p1(input: &InputPart1) -> usize {
     wheel[2025 % wheel.len()]
 }
 
-const INPUT_PART2: &str = include_str!("inputs/quest13-2.txt");
 type InputPart2<'a> = Vec<(usize, usize)>;
-fn parse_input_part2(input: &'_ str) -> InputPart2<'_> {
+fn parse_input_part2(input: &'_ str) -> parse::Result<InputPart2<'_>> {
     // For p2 and p3 we want to get the ranges instead.
     input
         .trim()
         .lines()
         .map(|l| {
-            l.split_once('-')
-                .map(|(l, r)| (l.parse::<usize>().unwrap(), r.parse::<usize>().unwrap()))
-                .unwrap()
+            let (l, r) = parse::split_once(input, l, "-")?;
+            Ok((parse::number(input, l)?, parse::number(input, r)?))
         })
         .collect()
 }
@@ -75,26 +74,42 @@ fn p3(input: &InputPart3) -> usize {
     spin_wheel(&make_wheel(input), 202520252025)
 }
 
-fn main() {
-    let now = Instant::now();
-    let input = parse_input_part1(INPUT_PART1);
-    let solution = p1(&input);
-    println!("p1 {:?} {}", now.elapsed(), solution);
-
-    let now = Instant::now();
-    let input = parse_input_part2(INPUT_PART2);
-    let solution = p2(&input);
-    println!("p2 {:?} {}", now.elapsed(), solution);
-
-    let now = Instant::now();
-    let input = parse_input_part3(INPUT_PART3);
-    let solution = p3(&input);
-    println!("p3 {:?} {}", now.elapsed(), solution);
+struct Quest;
+
+impl Solution for Quest {
+    type Part1<'a> = InputPart1<'a>;
+    type Part2<'a> = InputPart2<'a>;
+    type Part3<'a> = InputPart3<'a>;
+
+    fn parse_part1(input: &str) -> anyhow::Result<Self::Part1<'_>> {
+        Ok(parse_input_part1(input)?)
+    }
+
+    fn parse_part2(input: &str) -> anyhow::Result<Self::Part2<'_>> {
+        Ok(parse_input_part2(input)?)
+    }
+
+    fn parse_part3(input: &str) -> anyhow::Result<Self::Part3<'_>> {
+        Ok(parse_input_part3(input)?)
+    }
+
+    fn p1(input: &mut Self::Part1<'_>) -> impl Display {
+        p1(input)
+    }
+
+    fn p2(input: &mut Self::Part2<'_>) -> impl Display {
+        p2(input)
+    }
+
+    fn p3(input: &mut Self::Part3<'_>) -> impl Display {
+        p3(input)
+    }
 }
 
-const INPUT_PART3: &str = include_str!("inputs/quest13-3.txt");
+pub(crate) const ENTRY: Entry = Entry::new::<Quest>(crate::EVENT, 13);
+
 type InputPart3<'a> = InputPart2<'a>;
-fn parse_input_part3(input: &'_ str) -> InputPart3<'_> {
+fn parse_input_part3(input: &'_ str) -> parse::Result<InputPart3<'_>> {
     parse_input_part2(input)
 }
 
@@ -104,19 +119,13 @@ mod tests {
 
     #[test]
     fn test_p1() {
-        let input = parse_input_part1("72\n58\n47\n61\n67");
+        let input = parse_input_part1("72\n58\n47\n61\n67").unwrap();
         assert_eq!(p1(&input), 67);
     }
 
     #[test]
     fn test_p2() {
-        let input = parse_input_part2("10-15\n12-13\n20-21\n19-23\n30-37");
+        let input = parse_input_part2("10-15\n12-13\n20-21\n19-23\n30-37").unwrap();
         assert_eq!(p2(&input), 30);
     }
-
-    #[test]
-    fn test_p3() {
-        let input = parse_input_part3(INPUT_PART3);
-        assert_eq!(input, input);
-    }
 }
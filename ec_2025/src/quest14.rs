@@ -0,0 +1,379 @@
+use std::fmt::Display;
+
+use ec_common::{
+    BitGrid, Color, Entry, Picture, Point, Recorder, Simulation, Solution, Stepper,
+    cycle::{Cycle, CycleDetector},
+    parse,
+};
+
+use rustc_hash::FxHashSet;
+
+type InputPart1 = Grid;
+fn parse_input_part1(input: &str) -> parse::Result<InputPart1> {
+    Grid::parse(input)
+}
+
+// Active tiles are the set bits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Grid {
+    tiles: BitGrid,
+}
+
+impl Grid {
+    // Active tiles are `#` and inactive ones `.`.
+    fn parse(input: &str) -> parse::Result<Self> {
+        let tiles = BitGrid::try_parse_with(input, |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+        Ok(Self { tiles })
+    }
+
+    // used for p3 to make an empty grid.
+    fn new(size: usize) -> Self {
+        Self {
+            tiles: BitGrid::new(size, size),
+        }
+    }
+
+    const DIAGONALS: [(isize, isize); 4] = [(1, 1), (1, -1), (-1, 1), (-1, -1)];
+
+    fn next(&self) -> Self {
+        // A tile flips when it has an even number of active diagonals, which is when the
+        // diagonals shifted onto it xor to 0.
+        let mut tiles = self.tiles.clone();
+        for (dx, dy) in Self::DIAGONALS {
+            tiles ^= &self.tiles.shift(dx, dy);
+        }
+        tiles.invert();
+        Self { tiles }
+    }
+
+    fn value(&self) -> usize {
+        self.tiles.count()
+    }
+
+    fn center_matches(&self, center: &Grid) -> bool {
+        // Figure out where the center would start.
+        let (width, height) = (center.tiles.width(), center.tiles.height());
+        let offset = Point::new(
+            (self.tiles.width() - width) as isize / 2,
+            (self.tiles.height() - height) as isize / 2,
+        );
+
+        // Ensure all values are equal.
+        (0..height as isize)
+            .flat_map(|y| (0..width as isize).map(move |x| Point::new(x, y)))
+            .all(|p| self.tiles.get(p + offset) == center.tiles.get(p))
+    }
+}
+
+impl Display for Grid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.tiles)
+    }
+}
+
+impl Grid {
+    fn picture(&self) -> Picture {
+        let mut picture = Picture::new();
+        let size = Point::new(self.tiles.width(), self.tiles.height());
+        picture.area(Point::new(0, 0), size);
+        for p in self.tiles.iter() {
+            picture.cell(p, Color::BLACK);
+        }
+        picture
+    }
+
+    // Outline where the pattern should be, in red when it's there.
+    fn picture_with(&self, pattern: &Grid) -> Picture {
+        let (height, width) = (
+            pattern.tiles.height() as isize,
+            pattern.tiles.width() as isize,
+        );
+        let (sr, sc) = (
+            (self.tiles.height() as isize - height) / 2,
+            (self.tiles.width() as isize - width) / 2,
+        );
+        let corners = [
+            (0, 0),
+            (0, width - 1),
+            (height - 1, width - 1),
+            (height - 1, 0),
+            (0, 0),
+        ]
+        .map(|(r, c)| Point::new(sc + c, sr + r));
+
+        let mut picture = self.picture();
+        let color = match self.center_matches(pattern) {
+            true => Color::RED,
+            false => Color::GRAY,
+        };
+        picture.line(corners, color, 0.25);
+        picture
+    }
+}
+
+// We can do the same thing for p1 and p2.
+fn simulate(input: &Grid, rounds: usize) -> usize {
+    (0..rounds)
+        .scan(input.clone(), |grid, _| {
+            *grid = grid.next();
+            Some(grid.value())
+        })
+        .sum()
+}
+
+fn p1(input: &InputPart1) -> usize {
+    simulate(input, 10)
+}
+
+fn p2(input: &InputPart2) -> usize {
+    simulate(input, 2025)
+}
+
+fn p3(center: &InputPart3) -> usize {
+    const TOTAL_ROUNDS: usize = 1_000_000_000;
+    let mut grid = Grid::new(34);
+    let mut detector = CycleDetector::new(TOTAL_ROUNDS);
+
+    loop {
+        // Update our state and it's value.
+        grid = grid.next();
+        let value = if grid.center_matches(center) {
+            grid.value()
+        } else {
+            0
+        };
+
+        // Use the cycle detector and return when we find one.
+        if let Some(total) = detector.step(grid.clone(), value) {
+            return total;
+        }
+    }
+}
+
+// Parts 1 and 2 draw the floor after the last round. Part 3 draws the first round where the
+// pattern shows up in the middle, with the pattern outlined.
+fn render(part: u8, input: &InputPart1) -> Option<Picture> {
+    if part != 3 {
+        let rounds = if part == 1 { 10 } else { 2025 };
+        return Some(
+            (0..rounds)
+                .fold(input.clone(), |grid, _| grid.next())
+                .picture(),
+        );
+    }
+
+    let mut grid = Grid::new(34);
+    let mut seen = FxHashSet::default();
+    while seen.insert(grid.clone()) {
+        grid = grid.next();
+        if grid.center_matches(input) {
+            return Some(grid.picture_with(input));
+        }
+    }
+
+    // We went all the way around the cycle without seeing the pattern.
+    None
+}
+
+// Records the floor each round. Part 3 starts from an empty floor, outlines the pattern and notes
+// when the cycle shows up.
+fn animate(part: u8, input: &InputPart1, recorder: &mut Recorder) {
+    let (mut grid, rounds) = match part {
+        1 => (input.clone(), 10),
+        2 => (input.clone(), 2025),
+        _ => (Grid::new(34), 1_000_000_000),
+    };
+    let picture = |grid: &Grid| match part {
+        3 => grid.picture_with(input),
+        _ => grid.picture(),
+    };
+    let mut detector = CycleDetector::new(rounds);
+
+    recorder.record(0, || picture(&grid));
+    for round in 1..=rounds {
+        grid = grid.next();
+        recorder.record(round, || picture(&grid));
+
+        if part == 3 && detector.cycle().is_none() {
+            let value = match grid.center_matches(input) {
+                true => grid.value(),
+                false => 0,
+            };
+            detector.step(grid.clone(), value);
+            if let Some(Cycle { start, len }) = detector.cycle() {
+                recorder.note(format!(
+                    "round {round} is the same as round {start}, so the floor repeats every {len} rounds"
+                ));
+            }
+        }
+
+        if recorder.done(round) {
+            break;
+        }
+    }
+}
+
+// The floor for `ec debug`, adding up the active tiles the way each part counts them. Part 3 only
+// counts rounds where the pattern is in the middle.
+#[derive(Debug, Clone)]
+struct Floor {
+    grid: Grid,
+    pattern: Option<Grid>,
+    total: usize,
+}
+
+impl Floor {
+    fn new(part: u8, input: &Grid) -> Self {
+        match part {
+            3 => Self {
+                grid: Grid::new(34),
+                pattern: Some(input.clone()),
+                total: 0,
+            },
+            _ => Self {
+                grid: input.clone(),
+                pattern: None,
+                total: 0,
+            },
+        }
+    }
+
+    fn matches(&self) -> bool {
+        self.pattern
+            .as_ref()
+            .is_none_or(|p| self.grid.center_matches(p))
+    }
+}
+
+impl Simulation for Floor {
+    fn state(&self) -> String {
+        self.grid.to_string()
+    }
+
+    fn step(&mut self) -> bool {
+        self.grid = self.grid.next();
+        if self.matches() {
+            self.total += self.grid.value();
+        }
+        true
+    }
+
+    fn status(&self) -> String {
+        let mut status = format!("{} active, {} in total", self.grid.value(), self.total);
+        if self.pattern.is_some() && self.matches() {
+            status.push_str(", the pattern is in the middle");
+        }
+        status
+    }
+}
+
+struct Quest;
+
+impl Solution for Quest {
+    type Part1<'a> = InputPart1;
+    type Part2<'a> = InputPart2;
+    type Part3<'a> = InputPart3;
+
+    fn parse_part1(input: &str) -> anyhow::Result<Self::Part1<'_>> {
+        Ok(parse_input_part1(input)?)
+    }
+
+    fn parse_part2(input: &str) -> anyhow::Result<Self::Part2<'_>> {
+        Ok(parse_input_part2(input)?)
+    }
+
+    fn parse_part3(input: &str) -> anyhow::Result<Self::Part3<'_>> {
+        Ok(parse_input_part3(input)?)
+    }
+
+    fn p1(input: &mut Self::Part1<'_>) -> impl Display {
+        p1(input)
+    }
+
+    fn p2(input: &mut Self::Part2<'_>) -> impl Display {
+        p2(input)
+    }
+
+    fn p3(input: &mut Self::Part3<'_>) -> impl Display {
+        p3(input)
+    }
+
+    fn render(part: u8, input: &str) -> anyhow::Result<Option<Picture>> {
+        Ok(render(part, &parse_input_part1(input)?))
+    }
+
+    fn animate(part: u8, input: &str, recorder: &mut Recorder) -> anyhow::Result<()> {
+        animate(part, &parse_input_part1(input)?, recorder);
+        Ok(())
+    }
+
+    fn simulate(part: u8, input: &str) -> anyhow::Result<Option<Stepper>> {
+        Ok(Some(Stepper::new(Floor::new(
+            part,
+            &parse_input_part1(input)?,
+        ))))
+    }
+}
+
+pub(crate) const ENTRY: Entry = Entry::new::<Quest>(crate::EVENT, 14);
+
+type InputPart2 = InputPart1;
+fn parse_input_part2(input: &str) -> parse::Result<InputPart2> {
+    parse_input_part1(input)
+}
+
+type InputPart3 = InputPart1;
+fn parse_input_part3(input: &str) -> parse::Result<InputPart3> {
+    parse_input_part1(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_p1() {
+        let input = parse_input_part1(".#.##.\n##..#.\n..##.#\n.#.##.\n.###..\n###.##").unwrap();
+        assert_eq!(p1(&input), 200);
+
+        let err = parse_input_part1(".#.\n#.").map(|_| ()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line is 2 wide but the first is 3 at line 2 col 1"
+        );
+        let err = parse_input_part1(".#.\n#x.").map(|_| ()).unwrap_err();
+        assert_eq!(err.to_string(), "unexpected 'x' at line 2 col 2");
+    }
+
+    #[test]
+    fn test_simulate() {
+        let input = parse_input_part1(".#.##.\n##..#.\n..##.#\n.#.##.\n.###..\n###.##").unwrap();
+        let mut stepper = Stepper::new(Floor::new(1, &input));
+        stepper.seek(10);
+        assert!(stepper.status().ends_with("200 in total"));
+        stepper.back();
+        assert_eq!(
+            stepper.state(),
+            (0..9).fold(input, |grid, _| grid.next()).to_string()
+        );
+    }
+
+    #[test]
+    fn test_p2() {
+        let input = parse_input_part2(".#.##.\n##..#.\n..##.#\n.#.##.\n.###..\n###.##").unwrap();
+        assert_eq!(p2(&input), 39349);
+    }
+
+    #[test]
+    fn test_p3() {
+        let input = parse_input_part3(
+            "#......#\n..#..#..\n.##..##.\n...##...\n...##...\n.##..##.\n..#..#..\n#......#",
+        )
+        .unwrap();
+        assert_eq!(p3(&input), 278388552);
+    }
+}
@@ -0,0 +1,256 @@
+use std::fmt::Display;
+
+use ec_common::{
+    Color, Entry, ParseError, Picture, Point, Solution, parse,
+    point::{ADJACENT, ORTHOGONAL},
+    search::{Search, bfs, dijkstra},
+};
+
+use rustc_hash::FxHashSet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Turn {
+    Left,
+    Right,
+}
+
+type InputPart1<'a> = Vec<(Turn, isize)>;
+fn parse_input_part1(input: &'_ str) -> parse::Result<InputPart1<'_>> {
+    input
+        .trim()
+        .split(',')
+        .map(|v| {
+            let dir = match parse::char(input, v)? {
+                'L' => Turn::Left,
+                'R' => Turn::Right,
+                _ => return Err(ParseError::at(input, v, "expected L or R")),
+            };
+            Ok((dir, parse::number(input, &v[1..])?))
+        })
+        .collect()
+}
+
+// Turn our current heading based on the direction from the input.
+fn turn(delta: Point, dir: Turn) -> Point {
+    match dir {
+        Turn::Left => delta.turn_left(),
+        Turn::Right => delta.turn_right(),
+    }
+}
+
+// For p1 and p2, I was able to just build all the walls and then return the last point as the end.
+fn build_walls(input: &InputPart1) -> (FxHashSet<Point>, Point) {
+    let mut walls = FxHashSet::default();
+    let mut cur = Point::new(0, 0);
+    let mut delta = Point::new(0, -1);
+
+    for &(dir, dist) in input {
+        // Turn.
+        delta = turn(delta, dir);
+
+        // Add each point along the way as a wall.
+        (1isize..=dist).for_each(|v| {
+            walls.insert(cur + delta * v);
+        });
+
+        // Update our current position.
+        cur += delta * dist;
+    }
+
+    // Remove the end.
+    walls.remove(&cur);
+
+    (walls, cur)
+}
+
+fn p1(input: &InputPart1) -> usize {
+    let (walls, end) = build_walls(input);
+
+    // Since all distances are 1, we can simply bfs around the walls.
+    bfs(
+        [Point::default()],
+        |cur| cur.neighbors(&ORTHOGONAL).filter(|n| !walls.contains(n)),
+        |&cur| cur == end,
+    )
+    .goal_distance()
+    .unwrap()
+}
+
+fn p2(input: &InputPart2) -> usize {
+    // Just called p1 for p2, LOL.
+    p1(input)
+}
+
+struct Map {
+    start: Point,
+    end: Point,
+    walls: Vec<(Point, Point)>,
+    points_of_interest: FxHashSet<Point>,
+}
+
+impl Map {
+    fn from_input(input: &InputPart1) -> Self {
+        // We start by building our walls and tracking corners.
+        let mut walls = Vec::new();
+        let mut corners = Vec::new();
+        let mut delta = Point::new(0, -1);
+        let start = Point::default();
+        let mut next = start;
+        corners.push(start);
+
+        for &(dir, dist) in input {
+            delta = turn(delta, dir);
+            next += delta;
+            let begin = next;
+            next += delta * (dist - 2);
+            walls.push((begin, next));
+            next += delta;
+            corners.push(next);
+        }
+
+        // For each corner, we add it's non-wall neighbors as points of interest.
+        let points_of_interest = corners
+            .iter()
+            .flat_map(|corner| corner.neighbors(&ADJACENT))
+            .filter(|&point| Self::valid_move(&walls, point, point))
+            .chain([start, next]) // Also add start and end as points of interest.
+            .collect();
+
+        Self {
+            start,
+            end: next,
+            walls,
+            points_of_interest,
+        }
+    }
+
+    // Each neighbor is any point of interest that can be reached without crossing a wall.
+    fn neighbors(&self, from: Point) -> Vec<(Point, usize)> {
+        self.points_of_interest
+            .iter()
+            .copied()
+            .filter(|&to| to != from && Self::valid_move(&self.walls, from, to))
+            .map(|to| (to, from.manhattan(&to)))
+            .collect()
+    }
+
+    // Check if movement from one point to another crosses any wall
+    fn valid_move(walls: &[(Point, Point)], from: Point, to: Point) -> bool {
+        // This is basically checking bounding boxes. from->to is a rectangle and the wall is a really thin rectangle.
+        let x_min = from.x.min(to.x);
+        let x_max = from.x.max(to.x);
+        let y_min = from.y.min(to.y);
+        let y_max = from.y.max(to.y);
+
+        walls.iter().all(|&(w1, w2)| {
+            let wx_min = w1.x.min(w2.x);
+            let wx_max = w1.x.max(w2.x);
+            let wy_min = w1.y.min(w2.y);
+            let wy_max = w1.y.max(w2.y);
+            x_min > wx_max || x_max < wx_min || y_min > wy_max || y_max < wy_min
+        })
+    }
+}
+
+impl Map {
+    // Now we can just run Dijkstra's algorithm on the points of interest.
+    fn search(&self) -> Search<Point, usize> {
+        dijkstra(
+            [self.start],
+            |&from| self.neighbors(from),
+            |&pos| pos == self.end,
+        )
+    }
+}
+
+fn p3(input: &InputPart3) -> usize {
+    Map::from_input(input).search().goal_distance().unwrap()
+}
+
+// Parts 1 and 2 draw every wall. Part 3 draws the walls as lines, since they're too long to draw
+// a cell at a time, along with the route between the points of interest.
+fn render(part: u8, input: &InputPart1) -> Picture {
+    let mut picture = Picture::new();
+    let end = if part < 3 {
+        let (walls, end) = build_walls(input);
+        for wall in walls {
+            picture.cell(wall, Color::BLACK);
+        }
+        end
+    } else {
+        let map = Map::from_input(input);
+        for &(from, to) in &map.walls {
+            picture.line([from, to], Color::BLACK, 1.0);
+        }
+        picture.path(map.search().goal_path().unwrap(), Color::BLUE);
+        map.end
+    };
+    picture
+        .cell(Point::default(), Color::GREEN)
+        .cell(end, Color::RED);
+    picture
+}
+
+struct Quest;
+
+impl Solution for Quest {
+    type Part1<'a> = InputPart1<'a>;
+    type Part2<'a> = InputPart2<'a>;
+    type Part3<'a> = InputPart3<'a>;
+
+    fn parse_part1(input: &str) -> anyhow::Result<Self::Part1<'_>> {
+        Ok(parse_input_part1(input)?)
+    }
+
+    fn parse_part2(input: &str) -> anyhow::Result<Self::Part2<'_>> {
+        Ok(parse_input_part2(input)?)
+    }
+
+    fn parse_part3(input: &str) -> anyhow::Result<Self::Part3<'_>> {
+        Ok(parse_input_part3(input)?)
+    }
+
+    fn p1(input: &mut Self::Part1<'_>) -> impl Display {
+        p1(input)
+    }
+
+    fn p2(input: &mut Self::Part2<'_>) -> impl Display {
+        p2(input)
+    }
+
+    fn p3(input: &mut Self::Part3<'_>) -> impl Display {
+        p3(input)
+    }
+
+    fn render(part: u8, input: &str) -> anyhow::Result<Option<Picture>> {
+        Ok(Some(render(part, &parse_input_part1(input)?)))
+    }
+}
+
+pub(crate) const ENTRY: Entry = Entry::new::<Quest>(crate::EVENT, 15);
+
+type InputPart2<'a> = InputPart1<'a>;
+fn parse_input_part2(input: &'_ str) -> parse::Result<InputPart2<'_>> {
+    parse_input_part1(input)
+}
+
+type InputPart3<'a> = InputPart1<'a>;
+fn parse_input_part3(input: &'_ str) -> parse::Result<InputPart3<'_>> {
+    parse_input_part1(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_p1() {
+        let input =
+            parse_input_part1("L6,L3,L6,R3,L6,L3,L3,R6,L6,R6,L6,L6,R3,L3,L3,R3,R3,L6,L6,L3")
+                .unwrap();
+        assert_eq!(p1(&input), 16);
+
+        let error = parse_input_part1("X3,L2").unwrap_err();
+        assert_eq!(error.to_string(), "expected L or R at line 1 col 1");
+    }
+}
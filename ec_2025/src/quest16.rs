@@ -1,15 +1,16 @@
-use std::time::Instant;
+use std::fmt::Display;
+
+use ec_common::{Entry, Solution, math::divisors, parse};
 
 use num::Integer;
 use rustc_hash::FxHashSet;
 
-const INPUT_PART1: &str = include_str!("inputs/quest16-1.txt");
 type InputPart1<'a> = Vec<usize>;
-fn parse_input_part1(input: &'_ str) -> InputPart1<'_> {
+fn parse_input_part1(input: &'_ str) -> parse::Result<InputPart1<'_>> {
     input
         .trim()
         .split(',')
-        .map(|n| n.parse::<usize>().unwrap())
+        .map(|n| parse::number(input, n))
         .collect()
 }
 
@@ -19,18 +20,14 @@ fn p1(input: &InputPart1) -> usize {
         .sum()
 }
 
-fn factors(n: usize) -> impl Iterator<Item = usize> {
-    // The if block handles perfect squares.
-    (1..=((n as f64).sqrt() as usize))
-        .filter(move |i| n.is_multiple_of(*i))
-        .flat_map(move |i| if i != n / i { vec![i, n / i] } else { vec![i] })
-}
-
 fn spell_required(input: &InputPart2) -> FxHashSet<usize> {
     (1..)
         .zip(input)
         .fold(FxHashSet::default(), |mut seen, (n, &cur)| {
-            let used = factors(n).filter(|f| seen.contains(f)).count();
+            let used = divisors(n as u64)
+                .into_iter()
+                .filter(|&f| seen.contains(&(f as usize)))
+                .count();
             if cur > used {
                 seen.insert(n);
             }
@@ -72,32 +69,47 @@ fn bsearch(spell: &FxHashSet<usize>, total: usize) -> usize {
     low
 }
 
-fn main() {
-    let now = Instant::now();
-    let input = parse_input_part1(INPUT_PART1);
-    let solution = p1(&input);
-    println!("p1 {:?} {}", now.elapsed(), solution);
-
-    let now = Instant::now();
-    let input = parse_input_part2(INPUT_PART2);
-    let solution = p2(&input);
-    println!("p2 {:?} {}", now.elapsed(), solution);
-
-    let now = Instant::now();
-    let input = parse_input_part3(INPUT_PART3);
-    let solution = p3(&input);
-    println!("p3 {:?} {}", now.elapsed(), solution);
+struct Quest;
+
+impl Solution for Quest {
+    type Part1<'a> = InputPart1<'a>;
+    type Part2<'a> = InputPart2<'a>;
+    type Part3<'a> = InputPart3<'a>;
+
+    fn parse_part1(input: &str) -> anyhow::Result<Self::Part1<'_>> {
+        Ok(parse_input_part1(input)?)
+    }
+
+    fn parse_part2(input: &str) -> anyhow::Result<Self::Part2<'_>> {
+        Ok(parse_input_part2(input)?)
+    }
+
+    fn parse_part3(input: &str) -> anyhow::Result<Self::Part3<'_>> {
+        Ok(parse_input_part3(input)?)
+    }
+
+    fn p1(input: &mut Self::Part1<'_>) -> impl Display {
+        p1(input)
+    }
+
+    fn p2(input: &mut Self::Part2<'_>) -> impl Display {
+        p2(input)
+    }
+
+    fn p3(input: &mut Self::Part3<'_>) -> impl Display {
+        p3(input)
+    }
 }
 
-const INPUT_PART2: &str = include_str!("inputs/quest16-2.txt");
+pub(crate) const ENTRY: Entry = Entry::new::<Quest>(crate::EVENT, 16);
+
 type InputPart2<'a> = InputPart1<'a>;
-fn parse_input_part2(input: &'_ str) -> InputPart2<'_> {
+fn parse_input_part2(input: &'_ str) -> parse::Result<InputPart2<'_>> {
     parse_input_part1(input)
 }
 
-const INPUT_PART3: &str = include_str!("inputs/quest16-3.txt");
 type InputPart3<'a> = InputPart1<'a>;
-fn parse_input_part3(input: &'_ str) -> InputPart3<'_> {
+fn parse_input_part3(input: &'_ str) -> parse::Result<InputPart3<'_>> {
     parse_input_part1(input)
 }
 
@@ -107,19 +119,21 @@ mod tests {
 
     #[test]
     fn test_p1() {
-        let input = parse_input_part1("1,2,3,5,9");
+        let input = parse_input_part1("1,2,3,5,9").unwrap();
         assert_eq!(p1(&input), 193);
     }
 
     #[test]
     fn test_p2() {
-        let input = parse_input_part2("1,2,2,2,2,3,1,2,3,3,1,3,1,2,3,2,1,4,1,3,2,2,1,3,2,2");
+        let input =
+            parse_input_part2("1,2,2,2,2,3,1,2,3,3,1,3,1,2,3,2,1,4,1,3,2,2,1,3,2,2").unwrap();
         assert_eq!(p2(&input), 270);
     }
 
     #[test]
     fn test_p3() {
-        let input = parse_input_part3("1,2,2,2,2,3,1,2,3,3,1,3,1,2,3,2,1,4,1,3,2,2,1,3,2,2");
+        let input =
+            parse_input_part3("1,2,2,2,2,3,1,2,3,3,1,3,1,2,3,2,1,4,1,3,2,2,1,3,2,2").unwrap();
         assert_eq!(p3(&input), 94439495762954);
     }
 }
@@ -1,13 +1,10 @@
+use std::fmt::Display;
+
+use ec_common::{Color, Entry, ParseError, Picture, Point, Solution, parse, search::dijkstra};
+
 use itertools::Itertools;
 use rayon::prelude::*;
-use rustc_hash::{FxHashMap, FxHashSet};
-use std::cmp::Ordering;
-use std::collections::BinaryHeap;
-use std::time::Instant;
-
-const INPUT_PART1: &str = include_str!("inputs/quest17-1.txt");
-const INPUT_PART2: &str = include_str!("inputs/quest17-2.txt");
-const INPUT_PART3: &str = include_str!("inputs/quest17-3.txt");
+use rustc_hash::FxHashSet;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 struct Tile {
@@ -46,6 +43,7 @@ impl Tile {
     }
 }
 
+#[derive(Debug)]
 struct Grid {
     tiles: Vec<Vec<char>>,
     volcano: Tile,
@@ -53,33 +51,45 @@ struct Grid {
 }
 
 impl Grid {
-    fn parse(input: &str) -> Self {
-        let mut volcano = Tile::new(0, 0);
+    // Tiles are digits, apart from the volcano `@` and where we start `S`.
+    fn parse(input: &str) -> parse::Result<Self> {
+        let mut tiles: Vec<Vec<char>> = vec![];
+        let mut volcano = None;
         let mut start = Tile::new(0, 0);
 
         // Create the grid, but also track the volcano and start location.
-        let cells: Vec<Vec<char>> = (0isize..)
-            .zip(input.lines())
-            .map(|(row, line)| {
-                (0isize..)
-                    .zip(line.chars())
-                    .map(|(col, ch)| {
-                        if ch == '@' {
-                            volcano = Tile::new(row, col);
-                        } else if ch == 'S' {
-                            start = Tile::new(row, col);
-                        }
-                        ch
-                    })
-                    .collect()
-            })
-            .collect();
+        for (row, line) in (0isize..).zip(input.lines()) {
+            let mut cells = vec![];
+            for (col, s) in (0isize..).zip(parse::chars(line)) {
+                let ch = parse::char(input, s)?;
+                match ch {
+                    '@' => volcano = Some(Tile::new(row, col)),
+                    'S' => start = Tile::new(row, col),
+                    ch if ch.is_ascii_digit() => (),
+                    _ => return Err(ParseError::at(input, s, format!("unexpected '{ch}'"))),
+                }
+                cells.push(ch);
+            }
+            if let Some(first) = tiles.first()
+                && first.len() != cells.len()
+            {
+                let message = format!(
+                    "line is {} wide but the first is {}",
+                    cells.len(),
+                    first.len()
+                );
+                return Err(ParseError::at(input, line, message));
+            }
+            tiles.push(cells);
+        }
 
-        Self {
-            tiles: cells,
+        let volcano =
+            volcano.ok_or_else(|| ParseError::after(input, input, "there's no volcano"))?;
+        Ok(Self {
+            tiles,
             volcano,
             start,
-        }
+        })
     }
 
     fn height(&self) -> isize {
@@ -129,23 +139,24 @@ impl Grid {
     }
 }
 
-fn p1(input: &str) -> usize {
+fn p1(grid: &Grid) -> usize {
     // Just count destruction at radius 10.
-    Grid::parse(input).destruction(10)
+    grid.destruction(10)
 }
 
-fn p2(input: &str) -> usize {
-    let grid = Grid::parse(input);
-
-    // Get the destruction at each radius and find the largest. We make windows because the
-    // destruction of the current radius shouldn't include previous radii.
-    let (radius, destruction) = (1..=grid.max())
+// Get the destruction at each radius and find the largest. We make windows because the
+// destruction of the current radius shouldn't include previous radii.
+fn most_destructive(grid: &Grid) -> (isize, usize) {
+    (1..=grid.max())
         .map(|radius| (radius, grid.destruction(radius)))
         .tuple_windows()
         .map(|((_, d1), (r2, d2))| (r2, d2 - d1))
         .max_by_key(|&(_, destruction)| destruction)
-        .unwrap();
+        .unwrap()
+}
 
+fn p2(grid: &Grid) -> usize {
+    let (radius, destruction) = most_destructive(grid);
     radius as usize * destruction
 }
 
@@ -201,108 +212,135 @@ impl LoopState {
     }
 }
 
-// This is the node that our Dijkstra's algorithm will track.
-#[derive(Copy, Clone, Eq, PartialEq)]
-struct Node {
-    cost: usize,
-    state: LoopState,
-    prev: Tile,
+// Returns the cost of the loop and the tiles along it, starting and ending at `start`. Each node
+// also remembers the tile it came from so we never step straight back onto it.
+fn find_shortest_loop(grid: &Grid, lava: &FxHashSet<Tile>) -> Option<(usize, Vec<Tile>)> {
+    let start = LoopState::new(grid.start, &grid.volcano);
+    let search = dijkstra(
+        [(start, grid.start)],
+        |&(state, prev): &(LoopState, Tile)| {
+            state
+                .pos
+                .neighbors(grid.height(), grid.width())
+                .filter(|&neighbor| neighbor != prev)
+                .filter_map(|neighbor| match neighbor == grid.start {
+                    // We can only go back to the start once we've gone all the way around. It
+                    // keeps the winding so it's not mistaken for where we started.
+                    true => state.winding_complete().then_some((
+                        (
+                            LoopState {
+                                pos: neighbor,
+                                ..state
+                            },
+                            state.pos,
+                        ),
+                        0,
+                    )),
+                    false => (!lava.contains(&neighbor)).then(|| {
+                        (
+                            (state.next_state(neighbor, &grid.volcano), state.pos),
+                            grid.cost(&neighbor),
+                        )
+                    }),
+                })
+                .collect::<Vec<_>>()
+        },
+        |(state, _)| state.pos == grid.start && state.winding_complete(),
+    );
+
+    let path = search
+        .goal_path()?
+        .iter()
+        .map(|(state, _)| state.pos)
+        .collect();
+    Some((search.goal_distance()?, path))
 }
 
-impl Node {
-    fn new(cost: usize, state: LoopState, prev: Tile) -> Self {
-        Self { cost, state, prev }
-    }
+// We are going to try all radii and find the smallest one that can make a loop. Returns the
+// radius, how long the loop takes and the loop.
+fn smallest_loop(grid: &Grid) -> Option<(isize, usize, Vec<Tile>)> {
+    (1..=grid.max())
+        .into_par_iter()
+        .filter_map(|radius| {
+            let (time, path) = find_shortest_loop(grid, &grid.obstacles(radius))?;
+            Some((radius, time, path))
+        })
+        .filter(|&(radius, time, _)| time / 30 <= radius as usize)
+        .min_by_key(|&(radius, _, _)| radius)
 }
 
-impl Ord for Node {
-    fn cmp(&self, other: &Self) -> Ordering {
-        other.cost.cmp(&self.cost) // Min-heap
-    }
+fn p3(grid: &Grid) -> usize {
+    let (radius, time, _) = smallest_loop(grid).unwrap();
+    time * radius as usize
 }
 
-impl PartialOrd for Node {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+// Costs are shaded from light to dark. Each part shows the lava at the radius it picked and part
+// 3 adds the loop around it.
+fn render(part: u8, grid: &Grid) -> Option<Picture> {
+    let mut picture = Picture::from_rows(&grid.tiles, |&ch| {
+        let cost = ch.to_digit(10)?;
+        Some(Color::WHITE.mix(Color::BLACK, cost as f64 / 9.0))
+    });
+    let point = |tile: &Tile| Point::new(tile.col, tile.row);
+
+    let (radius, path) = match part {
+        1 => (10, None),
+        2 => (most_destructive(grid).0, None),
+        _ => {
+            let (radius, _, path) = smallest_loop(grid)?;
+            (radius, Some(path))
+        }
+    };
+    for tile in grid.obstacles(radius) {
+        picture.cell(point(&tile), Color::ORANGE);
+    }
+    picture
+        .cell(point(&grid.volcano), Color::RED)
+        .cell(point(&grid.start), Color::GREEN);
+    if let Some(path) = path {
+        picture.path(path.iter().map(point), Color::BLUE);
     }
+    Some(picture)
 }
 
-fn find_shortest_loop(grid: &Grid, lava: &FxHashSet<Tile>) -> Option<usize> {
-    // Track our nodes to visit, prioritizing lower costs. Start with neighbors of `start`.
-    let mut frontier = grid
-        .start
-        .neighbors(grid.height(), grid.width())
-        .filter(|n| !lava.contains(n))
-        .map(|n| Node::new(grid.cost(&n), LoopState::new(n, &grid.volcano), grid.start))
-        .collect::<BinaryHeap<_>>();
-
-    // Track our known distances
-    let mut distances = FxHashMap::<LoopState, usize>::default();
-
-    // Grab the lowest cost node until we find the shortest path or run out.
-    while let Some(Node { cost, state, prev }) = frontier.pop() {
-        // If we've been here before, we only want to try it if we
-        // have a new better cost.
-        if let Some(&prev) = distances.get(&state)
-            && cost >= prev
-        {
-            continue;
-        }
+struct Quest;
 
-        // Track our distance to this state.
-        distances.insert(state, cost);
+impl Solution for Quest {
+    type Part1<'a> = Grid;
+    type Part2<'a> = Grid;
+    type Part3<'a> = Grid;
 
-        // Explore neighbors.
-        for neighbor in state.pos.neighbors(grid.height(), grid.width()) {
-            // We check for a winning solution and return it if we have one.
-            if neighbor == grid.start && state.winding_complete() {
-                return Some(cost);
-            }
-
-            // We don't want to backtrack, explore start, or an obstacle.
-            if neighbor == prev || neighbor == grid.start || lava.contains(&neighbor) {
-                continue;
-            }
-
-            // Add our neighbor node to frontier.
-            frontier.push(Node::new(
-                cost + grid.cost(&neighbor),
-                state.next_state(neighbor, &grid.volcano),
-                state.pos,
-            ));
-        }
+    fn parse_part1(input: &str) -> anyhow::Result<Self::Part1<'_>> {
+        Ok(Grid::parse(input)?)
     }
 
-    None
-}
+    fn parse_part2(input: &str) -> anyhow::Result<Self::Part2<'_>> {
+        Ok(Grid::parse(input)?)
+    }
 
-fn p3(input: &str) -> usize {
-    let grid = Grid::parse(input);
+    fn parse_part3(input: &str) -> anyhow::Result<Self::Part3<'_>> {
+        Ok(Grid::parse(input)?)
+    }
 
-    // We are going to try all radii and find the smallest one that can make a loop.
-    (1..=grid.max())
-        .into_par_iter()
-        .filter_map(|radius| Some((radius, find_shortest_loop(&grid, &grid.obstacles(radius))?)))
-        .filter(|&(radius, time)| time / 30 <= radius as usize)
-        .min_by_key(|&(radius, _)| radius)
-        .map(|(radius, time)| time * radius as usize)
-        .unwrap()
-}
+    fn p1(input: &mut Self::Part1<'_>) -> impl Display {
+        p1(input)
+    }
 
-fn main() {
-    let now = Instant::now();
-    let solution = p1(INPUT_PART1);
-    println!("p1 {:?} {}", now.elapsed(), solution);
+    fn p2(input: &mut Self::Part2<'_>) -> impl Display {
+        p2(input)
+    }
 
-    let now = Instant::now();
-    let solution = p2(INPUT_PART2);
-    println!("p2 {:?} {}", now.elapsed(), solution);
+    fn p3(input: &mut Self::Part3<'_>) -> impl Display {
+        p3(input)
+    }
 
-    let now = Instant::now();
-    let solution = p3(INPUT_PART3);
-    println!("p3 {:?} {}", now.elapsed(), solution);
+    fn render(part: u8, input: &str) -> anyhow::Result<Option<Picture>> {
+        Ok(render(part, &Grid::parse(input)?))
+    }
 }
 
+pub(crate) const ENTRY: Entry = Entry::new::<Quest>(crate::EVENT, 17);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -310,30 +348,59 @@ mod tests {
     #[test]
     fn test_p1() {
         let input = "189482189843433862719\n279415473483436249988\n432746714658787816631\n428219317375373724944\n938163982835287292238\n627369424372196193484\n539825864246487765271\n517475755641128575965\n685934212385479112825\n815992793826881115341\n1737798467@7983146242\n867597735651751839244\n868364647534879928345\n519348954366296559425\n134425275832833829382\n764324337429656245499\n654662236199275446914\n317179356373398118618\n542673939694417586329\n987342622289291613318\n971977649141188759131";
-        assert_eq!(p1(input), 1573);
+        assert_eq!(p1(&Grid::parse(input).unwrap()), 1573);
+
+        let error = Grid::parse("12\n3@4").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line is 3 wide but the first is 2 at line 2 col 1"
+        );
+        let error = Grid::parse("").unwrap_err();
+        assert_eq!(error.to_string(), "there's no volcano at line 1 col 1");
     }
 
     #[test]
     fn test_p2() {
         let input = "4547488458944\n9786999467759\n6969499575989\n7775645848998\n6659696497857\n5569777444746\n968586@767979\n6476956899989\n5659745697598\n6874989897744\n6479994574886\n6694118785585\n9568991647449";
-        assert_eq!(p2(input), 1090);
+        assert_eq!(p2(&Grid::parse(input).unwrap()), 1090);
     }
 
     #[test]
     fn test_p3() {
         let input = "2645233S5466644\n634566343252465\n353336645243246\n233343552544555\n225243326235365\n536334634462246\n666344656233244\n6426432@2366453\n364346442652235\n253652463426433\n426666225623563\n555462553462364\n346225464436334\n643362324542432\n463332353552464";
-        assert_eq!(p3(input), 592);
+        assert_eq!(p3(&Grid::parse(input).unwrap()), 592);
+    }
+
+    #[test]
+    fn test_render() {
+        let input = "2645233S5466644\n634566343252465\n353336645243246\n233343552544555\n225243326235365\n536334634462246\n666344656233244\n6426432@2366453\n364346442652235\n253652463426433\n426666225623563\n555462553462364\n346225464436334\n643362324542432\n463332353552464";
+        let grid = Grid::parse(input).unwrap();
+        let (_, time, path) = smallest_loop(&grid).unwrap();
+
+        // The loop goes from start back to start one step at a time and costs what we found.
+        assert_eq!(path.first(), Some(&grid.start));
+        assert_eq!(path.last(), Some(&grid.start));
+        assert!(
+            path.windows(2)
+                .all(|w| w[0].neighbors(15, 15).any(|n| n == w[1]))
+        );
+        assert_eq!(path.iter().map(|t| grid.cost(t)).sum::<usize>(), time);
+
+        // It never steps straight back to the tile it just left.
+        assert!(path.windows(3).all(|w| w[0] != w[2]));
+
+        assert_eq!(render(3, &grid).map(|p| p.size()), Some((120, 120)));
     }
 
     #[test]
     fn test_p3_big() {
         let input = "545233443422255434324\n5222533434S2322342222\n523444354223232542432\n553522225435232255242\n232343243532432452524\n245245322252324442542\n252533232225244224355\n523533554454232553332\n522332223232242523223\n524523432425432244432\n3532242243@4323422334\n542524223994422443222\n252343244322522222332\n253355425454255523242\n344324325233443552555\n423523225325255345522\n244333345244325322335\n242244352245522323422\n443332352222535334325\n323532222353523253542\n553545434425235223552";
-        assert_eq!(p3(input), 330);
+        assert_eq!(p3(&Grid::parse(input).unwrap()), 330);
     }
 
     #[test]
     fn test_p3_bigger() {
         let input = "5441525241225111112253553251553\n133522122534119S911411222155114\n3445445533355599933443455544333\n3345333555434334535435433335533\n5353333345335554434535533555354\n3533533435355443543433453355553\n3553353435335554334453355435433\n5435355533533355533535335345335\n4353545353545354555534334453353\n4454543553533544443353355553453\n5334554534533355333355543533454\n4433333345445354553533554555533\n5554454343455334355445533453453\n4435554534445553335434455334353\n3533435453433535345355533545555\n534433533533535@353533355553345\n4453545555435334544453344455554\n4353333535535354535353353535355\n4345444453554554535355345343354\n3534544535533355333333445433555\n3535333335335334333534553543535\n5433355333553344355555344553435\n5355535355535334555435534555344\n3355433335553553535334544544333\n3554333535553335343555345553535\n3554433545353554334554345343343\n5533353435533535333355343333555\n5355555353355553535354333535355\n4344534353535455333455353335333\n5444333535533453535335454535553\n3534343355355355553543545553345";
-        assert_eq!(p3(input), 3180);
+        assert_eq!(p3(&Grid::parse(input).unwrap()), 3180);
     }
 }
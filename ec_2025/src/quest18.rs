@@ -0,0 +1,539 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use ec_common::{Entry, ParseError, Solution, parse};
+
+#[derive(Debug, Clone)]
+enum Branch {
+    Free(isize),
+    Connected(isize, usize),
+}
+
+impl Branch {
+    // Either `- free branch with thickness 1` or `- branch to Plant 1 with thickness 15`.
+    fn parse(input: &str, line: &str) -> parse::Result<Self> {
+        let parts = line.split_whitespace().collect::<Vec<_>>();
+        let part = |i: usize| {
+            let part = parts.get(i).copied();
+            part.ok_or_else(|| ParseError::after(input, line, "branch is too short"))
+        };
+        match part(1)? {
+            "branch" => Ok(Self::Connected(
+                parse::number(input, part(7)?)?,
+                parse::number(input, part(4)?)?,
+            )),
+            "free" => Ok(Self::Free(parse::number(input, part(5)?)?)),
+            _ => Err(ParseError::at(input, line, "expected a branch")),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Plant {
+    thickness: isize,
+    branches: Vec<Branch>,
+}
+
+impl Plant {
+    fn parse(input: &str, s: &str) -> parse::Result<Self> {
+        let mut lines = s.lines();
+        let header = parse::next(input, s, &mut lines, "plant")?;
+        let mut parts = header.trim_end_matches(':').split_whitespace().skip(4);
+        let thickness = parse::number(input, parse::next(input, header, &mut parts, "thickness")?)?;
+        let branches = lines
+            .map(|l| Branch::parse(input, l))
+            .collect::<parse::Result<_>>()?;
+        Ok(Self {
+            thickness,
+            branches,
+        })
+    }
+
+    fn energy(&self, plants: &[Plant]) -> isize {
+        // Find the incoming energy recursively.
+        let incoming = self
+            .branches
+            .iter()
+            .map(|b| match b {
+                Branch::Free(thickness) => *thickness,
+                Branch::Connected(thickness, branch) => {
+                    plants[branch - 1].energy(plants) * thickness
+                }
+            })
+            .sum::<isize>();
+
+        if incoming < self.thickness {
+            0
+        } else {
+            incoming
+        }
+    }
+}
+
+struct Garden {
+    plants: Vec<Plant>,
+}
+
+impl IndexMut<usize> for Garden {
+    fn index_mut(&mut self, i: usize) -> &mut Self::Output {
+        &mut self.plants[i]
+    }
+}
+
+impl Index<usize> for Garden {
+    type Output = Plant;
+    fn index(&self, i: usize) -> &Plant {
+        &self.plants[i]
+    }
+}
+
+impl Garden {
+    fn new(plants: Vec<Plant>) -> Self {
+        Self { plants }
+    }
+
+    // p1 - what is the energy output of last plant.
+    fn energy(&self) -> isize {
+        self.plants.last().unwrap().energy(&self.plants)
+    }
+
+    fn energy_test(&mut self, test: &[isize]) -> isize {
+        // Modify the initial plants and then run the logic from p1.
+        test.iter()
+            .enumerate()
+            .for_each(|(i, t)| self[i].branches[0] = Branch::Free(*t));
+        self.energy()
+    }
+
+    fn len(&self) -> usize {
+        self.plants.len()
+    }
+}
+
+type Input = (Garden, Vec<Vec<isize>>);
+fn parse(input: &str) -> parse::Result<Input> {
+    let mut parts = input.split("\n\n\n");
+    let plants = parse::next(input, input, &mut parts, "plants")?
+        .split("\n\n")
+        .map(|p| Plant::parse(input, p))
+        .collect::<parse::Result<Vec<_>>>()?;
+    let tests = parts
+        .next()
+        .map(|s| {
+            s.lines()
+                .map(|line| {
+                    line.split_whitespace()
+                        .map(|c| parse::number(input, c))
+                        .collect()
+                })
+                .collect::<parse::Result<_>>()
+        })
+        .transpose()?
+        .unwrap_or_default();
+    Ok((Garden::new(plants), tests))
+}
+
+fn p1((garden, _): &Input) -> isize {
+    garden.energy()
+}
+
+fn p2((garden, tests): &mut Input) -> isize {
+    tests.iter().map(|t| garden.energy_test(t)).sum()
+}
+
+// Finds the most energy the last plant can get by switching the free branches of the first `free`
+// plants on or off.
+trait Backend {
+    fn max_energy(&self, garden: &Garden, free: usize) -> isize;
+}
+
+// Everything but the max is the same whichever backend found it. Tests with no energy don't count.
+fn p3_with(backend: &dyn Backend, (garden, tests): &mut Input) -> isize {
+    let max = backend.max_energy(garden, tests[0].len());
+    tests
+        .iter()
+        .map(|t| garden.energy_test(t))
+        .filter(|energy| *energy > 0)
+        .map(|energy| max - energy)
+        .sum()
+}
+
+// 2^81, lol, see you at the heat death of the universe. Branch and bound gets there a lot sooner.
+#[cfg(not(feature = "z3"))]
+const BACKEND: &dyn Backend = &BranchAndBound;
+#[cfg(feature = "z3")]
+const BACKEND: &dyn Backend = &Z3;
+
+fn p3(input: &mut Input) -> isize {
+    p3_with(BACKEND, input)
+}
+
+// Tries switching each branch on and off in turn, skipping the rest of the switches whenever the
+// most energy the last plant could get is no better than what we've already found.
+#[cfg_attr(feature = "z3", allow(dead_code))]
+struct BranchAndBound;
+
+#[cfg_attr(feature = "z3", allow(dead_code))]
+impl BranchAndBound {
+    // Guess by switching on the branches that feed more into positive branches than negative ones.
+    // That's usually the answer already, so most of the search gets cut short.
+    fn guess(garden: &Garden, free: usize) -> Vec<Option<bool>> {
+        (0..free)
+            .map(|i| {
+                let feeds = (free..garden.len())
+                    .flat_map(|plant| &garden[plant].branches)
+                    .filter_map(|branch| match branch {
+                        Branch::Connected(thickness, from) if from - 1 == i => Some(*thickness),
+                        _ => None,
+                    })
+                    .sum::<isize>();
+                Some(feeds > 0)
+            })
+            .collect()
+    }
+
+    // The least and most energy each plant could end up with, where the switches that are `None`
+    // could go either way. Plants only have branches from the plants before them.
+    fn bounds(garden: &Garden, switches: &[Option<bool>]) -> Vec<(isize, isize)> {
+        let mut bounds: Vec<(isize, isize)> = Vec::with_capacity(garden.len());
+        for (i, plant) in garden.plants.iter().enumerate() {
+            let (mut lo, mut hi) = (0, 0);
+            for (j, branch) in plant.branches.iter().enumerate() {
+                let (l, h) = match (branch, switches.get(i)) {
+                    (_, Some(&Some(on))) if j == 0 => (on as isize, on as isize),
+                    (_, Some(None)) if j == 0 => (0, 1),
+                    (Branch::Free(thickness), _) => (*thickness, *thickness),
+                    (Branch::Connected(thickness, from), _) => {
+                        let (l, h) = bounds[from - 1];
+                        (
+                            (l * thickness).min(h * thickness),
+                            (l * thickness).max(h * thickness),
+                        )
+                    }
+                };
+                lo += l;
+                hi += h;
+            }
+
+            // Below the plant's thickness it gets nothing, otherwise it gets everything.
+            bounds.push(match (lo < plant.thickness, hi >= plant.thickness) {
+                (true, true) => (plant.thickness.min(0), hi.max(0)),
+                (true, false) => (0, 0),
+                (false, _) => (lo, hi),
+            });
+        }
+        bounds
+    }
+
+    // Whether more energy in each plant always means more (1) or less (-1) energy in the last
+    // plant, or makes no difference (0). `None` when it depends on the other plants.
+    fn effects(garden: &Garden) -> Vec<Option<isize>> {
+        let mut effects = vec![Some(0); garden.len()];
+        effects[garden.len() - 1] = Some(1);
+        for (i, plant) in garden.plants.iter().enumerate().rev() {
+            // A plant's energy only goes up with what comes in when it can't go negative.
+            let effect = match plant.thickness >= 0 {
+                true => effects[i],
+                false => effects[i].filter(|&e| e == 0),
+            };
+            for branch in &plant.branches {
+                if let Branch::Connected(thickness, from) = branch {
+                    let combined = match (effects[from - 1], effect.map(|e| e * thickness.signum()))
+                    {
+                        (Some(0), e) | (e, Some(0)) => e,
+                        (Some(a), Some(b)) if a == b => Some(a),
+                        _ => None,
+                    };
+                    effects[from - 1] = combined;
+                }
+            }
+        }
+        effects
+    }
+
+    fn search(garden: &Garden, switches: &mut [Option<bool>], order: &[usize], best: &mut isize) {
+        let (_, most) = *Self::bounds(garden, switches).last().unwrap();
+        if most <= *best {
+            return;
+        }
+        let Some((&i, rest)) = order.split_first() else {
+            // Everything's switched, so the bounds are exact.
+            *best = most;
+            return;
+        };
+
+        // Try whichever way looks more promising first.
+        let mut most_if = |on| {
+            switches[i] = Some(on);
+            Self::bounds(garden, switches).last().unwrap().1
+        };
+        let ways = match most_if(true) >= most_if(false) {
+            true => [true, false],
+            false => [false, true],
+        };
+        for on in ways {
+            switches[i] = Some(on);
+            Self::search(garden, switches, rest, best);
+        }
+        switches[i] = None;
+    }
+}
+
+impl Backend for BranchAndBound {
+    fn max_energy(&self, garden: &Garden, free: usize) -> isize {
+        let guess = Self::guess(garden, free);
+        let mut best = Self::bounds(garden, &guess).last().unwrap().0;
+
+        // Switches that always help or always hurt don't need searching. The rest are tried a
+        // plant at a time, so each plant's energy is pinned down as soon as possible.
+        let effects = Self::effects(garden);
+        let mut switches = (0..free)
+            .map(|i| effects[i].map(|e| e > 0))
+            .collect::<Vec<_>>();
+        let first_fed = |i: usize| {
+            (free..garden.len()).find(|&plant| {
+                garden[plant]
+                    .branches
+                    .iter()
+                    .any(|b| matches!(b, Branch::Connected(_, from) if from - 1 == i))
+            })
+        };
+        let mut order = (0..free)
+            .filter(|&i| switches[i].is_none())
+            .collect::<Vec<_>>();
+        order.sort_by_key(|&i| first_fed(i));
+
+        Self::search(garden, &mut switches, &order, &mut best);
+        best
+    }
+}
+
+// Hands the whole thing to z3.
+#[cfg(feature = "z3")]
+struct Z3;
+
+#[cfg(feature = "z3")]
+impl Backend for Z3 {
+    fn max_energy(&self, garden: &Garden, free_branches: usize) -> isize {
+        use z3::{
+            Optimize,
+            ast::{Bool, Int},
+        };
+
+        let opt = Optimize::new();
+        let one = Int::from_i64(1);
+        let zero = Int::from_i64(0);
+
+        // Create boolean variables for each free branch
+        let free_branch_vars: Vec<Bool> = (0..free_branches)
+            .map(|i| Bool::new_const(format!("fb_{}", i)))
+            .collect();
+
+        // Create integer variables for each plant's energy.
+        let plant_energies: Vec<Int> = (0..garden.len())
+            .map(|i| Int::new_const(format!("plant_{}", i)))
+            .collect();
+
+        // For free branch plants, assert energy is 0 or 1.
+        for i in 0..free_branches {
+            let energy_one = plant_energies[i].eq(&one);
+            let energy_zero = plant_energies[i].eq(&zero);
+            opt.assert(&free_branch_vars[i].ite(&energy_one, &energy_zero));
+        }
+
+        // For other plants, calculate their energy based on branches
+        for i in free_branches..garden.len() {
+            let plant = &garden[i];
+
+            // Calculate incoming energy
+            let mut incoming = Int::from_i64(0);
+            for branch in &plant.branches {
+                match branch {
+                    Branch::Free(thickness) => {
+                        incoming += Int::from_i64(*thickness as i64);
+                    }
+                    Branch::Connected(thickness, source_idx) => {
+                        let source_energy = &plant_energies[source_idx - 1];
+                        let contribution = source_energy * Int::from_i64(*thickness as i64);
+                        incoming += contribution;
+                    }
+                }
+            }
+
+            // Energy is incoming if incoming >= thickness, else 0
+            let threshold = Int::from_i64(plant.thickness as i64);
+            let activated = incoming.ge(&threshold);
+            let zero = Int::from_i64(0);
+            opt.assert(&activated.ite(
+                &plant_energies[i].eq(&incoming),
+                &plant_energies[i].eq(&zero),
+            ));
+
+            // Also ensure energy is non-negative
+            opt.assert(&plant_energies[i].ge(&zero));
+        }
+
+        // Maximize the energy of the last plant
+        let last_plant_energy = &plant_energies[garden.len() - 1];
+        opt.maximize(last_plant_energy);
+
+        // Check satisfiability and get maximum
+        if opt.check(&[]) == z3::SatResult::Sat {
+            let model = opt.get_model().unwrap();
+            model
+                .eval(last_plant_energy, true)
+                .unwrap()
+                .as_i64()
+                .unwrap() as isize
+        } else {
+            panic!("No solution found");
+        }
+    }
+}
+
+struct Quest;
+
+impl Solution for Quest {
+    type Part1<'a> = Input;
+    type Part2<'a> = Input;
+    type Part3<'a> = Input;
+
+    fn parse_part1(input: &str) -> anyhow::Result<Self::Part1<'_>> {
+        Ok(parse(input)?)
+    }
+
+    fn parse_part2(input: &str) -> anyhow::Result<Self::Part2<'_>> {
+        Ok(parse(input)?)
+    }
+
+    fn parse_part3(input: &str) -> anyhow::Result<Self::Part3<'_>> {
+        Ok(parse(input)?)
+    }
+
+    fn p1(input: &mut Self::Part1<'_>) -> impl Display {
+        p1(input)
+    }
+
+    fn p2(input: &mut Self::Part2<'_>) -> impl Display {
+        p2(input)
+    }
+
+    fn p3(input: &mut Self::Part3<'_>) -> impl Display {
+        p3(input)
+    }
+}
+
+pub(crate) const ENTRY: Entry = Entry::new::<Quest>(crate::EVENT, 18);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Part 3's example, solved by each backend.
+    const P3_EXAMPLE: &str = "Plant 1 with thickness 1:
+- free branch with thickness 1
+
+Plant 2 with thickness 1:
+- free branch with thickness 1
+
+Plant 3 with thickness 1:
+- free branch with thickness 1
+
+Plant 4 with thickness 1:
+- free branch with thickness 1
+
+Plant 5 with thickness 8:
+- branch to Plant 1 with thickness 11
+- branch to Plant 2 with thickness 13
+- branch to Plant 3 with thickness 9
+
+Plant 6 with thickness 7:
+- branch to Plant 4 with thickness -14
+- branch to Plant 4 with thickness -9
+
+Plant 7 with thickness 23:
+- branch to Plant 5 with thickness 17
+- branch to Plant 6 with thickness 18
+
+
+0 1 0 0
+0 1 0 1
+1 1 1 0";
+
+    #[test]
+    fn test_p1() {
+        let input = "Plant 1 with thickness 1:
+- free branch with thickness 1
+
+Plant 2 with thickness 1:
+- free branch with thickness 1
+
+Plant 3 with thickness 1:
+- free branch with thickness 1
+
+Plant 4 with thickness 17:
+- branch to Plant 1 with thickness 15
+- branch to Plant 2 with thickness 3
+
+Plant 5 with thickness 24:
+- branch to Plant 2 with thickness 11
+- branch to Plant 3 with thickness 13
+
+Plant 6 with thickness 15:
+- branch to Plant 3 with thickness 14
+
+Plant 7 with thickness 10:
+- branch to Plant 4 with thickness 15
+- branch to Plant 5 with thickness 21
+- branch to Plant 6 with thickness 34";
+        assert_eq!(p1(&parse(input).unwrap()), 774);
+    }
+
+    #[test]
+    fn test_p2() {
+        let input = "Plant 1 with thickness 1:
+- free branch with thickness 1
+
+Plant 2 with thickness 1:
+- free branch with thickness 1
+
+Plant 3 with thickness 1:
+- free branch with thickness 1
+
+Plant 4 with thickness 10:
+- branch to Plant 1 with thickness -25
+- branch to Plant 2 with thickness 17
+- branch to Plant 3 with thickness 12
+
+Plant 5 with thickness 14:
+- branch to Plant 1 with thickness 14
+- branch to Plant 2 with thickness -26
+- branch to Plant 3 with thickness 15
+
+Plant 6 with thickness 150:
+- branch to Plant 4 with thickness 5
+- branch to Plant 5 with thickness 6
+
+
+1 0 1
+0 0 1
+0 1 1";
+        assert_eq!(p2(&mut parse(input).unwrap()), 324);
+    }
+
+    #[test]
+    fn test_p3_branch_and_bound() {
+        assert_eq!(
+            p3_with(&BranchAndBound, &mut parse(P3_EXAMPLE).unwrap()),
+            680
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "z3")]
+    fn test_p3_z3() {
+        assert_eq!(p3_with(&Z3, &mut parse(P3_EXAMPLE).unwrap()), 680);
+    }
+}
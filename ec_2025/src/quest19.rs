@@ -1,12 +1,10 @@
-use std::{ops::RangeInclusive, time::Instant};
+use std::{fmt::Display, ops::RangeInclusive};
+
+use ec_common::{Entry, Solution, parse};
 
 use itertools::Itertools;
 use num::Integer;
 
-const INPUT_PART1: &str = include_str!("inputs/quest19-1.txt");
-const INPUT_PART2: &str = include_str!("inputs/quest19-2.txt");
-const INPUT_PART3: &str = include_str!("inputs/quest19-3.txt");
-
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 struct Opening {
     dist: isize,
@@ -14,19 +12,24 @@ struct Opening {
     size: isize,
 }
 
-impl From<&str> for Opening {
-    fn from(value: &str) -> Self {
-        let mut parts = value.split(',').map(|p| p.parse::<isize>().unwrap());
-        Opening {
-            dist: parts.next().unwrap(),
-            start: parts.next().unwrap(),
-            size: parts.next().unwrap(),
-        }
+impl Opening {
+    fn parse(input: &str, line: &str) -> parse::Result<Self> {
+        let mut parts = line.split(',');
+        let mut next = |what| parse::number(input, parse::next(input, line, &mut parts, what)?);
+        Ok(Opening {
+            dist: next("distance")?,
+            start: next("start")?,
+            size: next("size")?,
+        })
     }
 }
 
-fn parse(input: &str) -> Vec<Opening> {
-    input.trim().lines().map(Opening::from).collect()
+fn parse(input: &str) -> parse::Result<Vec<Opening>> {
+    input
+        .trim()
+        .lines()
+        .map(|l| Opening::parse(input, l))
+        .collect()
 }
 
 #[derive(Debug, Clone)]
@@ -44,9 +47,9 @@ impl Wall {
     }
 
     // Create all the Walls with the openings merged (assumes input is sorted (it is)).
-    fn layout(openings: Vec<Opening>) -> Vec<Self> {
+    fn layout(openings: &[Opening]) -> Vec<Self> {
         openings
-            .into_iter()
+            .iter()
             .fold(Vec::new(), |mut acc: Vec<Wall>, opening| {
                 let gap = Range::new(opening.start, opening.start + opening.size - 1);
                 // If the new gap is in our last wall, add it, otherwise make a new wall.
@@ -156,36 +159,56 @@ impl Reachables {
     }
 }
 
-fn p1(input: &str) -> isize {
+fn p1(openings: &[Opening]) -> isize {
     // Now we can just go from one `Reachables` to another and then find the `solution()` from the last one.
-    Wall::layout(parse(input))
+    Wall::layout(openings)
         .iter()
         .fold(Reachables::new(), |acc, wall| acc.next(wall))
         .solution()
 }
 
-fn p2(input: &str) -> isize {
-    p1(input)
+fn p2(openings: &[Opening]) -> isize {
+    p1(openings)
 }
 
-fn p3(input: &str) -> isize {
-    p1(input)
+fn p3(openings: &[Opening]) -> isize {
+    p1(openings)
 }
 
-fn main() {
-    let now = Instant::now();
-    let solution = p1(INPUT_PART1);
-    println!("p1 {:?} {}", now.elapsed(), solution);
+struct Quest;
+
+impl Solution for Quest {
+    type Part1<'a> = Vec<Opening>;
+    type Part2<'a> = Vec<Opening>;
+    type Part3<'a> = Vec<Opening>;
+
+    fn parse_part1(input: &str) -> anyhow::Result<Self::Part1<'_>> {
+        Ok(parse(input)?)
+    }
+
+    fn parse_part2(input: &str) -> anyhow::Result<Self::Part2<'_>> {
+        Ok(parse(input)?)
+    }
+
+    fn parse_part3(input: &str) -> anyhow::Result<Self::Part3<'_>> {
+        Ok(parse(input)?)
+    }
+
+    fn p1(input: &mut Self::Part1<'_>) -> impl Display {
+        p1(input)
+    }
 
-    let now = Instant::now();
-    let solution = p2(INPUT_PART2);
-    println!("p2 {:?} {}", now.elapsed(), solution);
+    fn p2(input: &mut Self::Part2<'_>) -> impl Display {
+        p2(input)
+    }
 
-    let now = Instant::now();
-    let solution = p3(INPUT_PART3);
-    println!("p3 {:?} {}", now.elapsed(), solution);
+    fn p3(input: &mut Self::Part3<'_>) -> impl Display {
+        p3(input)
+    }
 }
 
+pub(crate) const ENTRY: Entry = Entry::new::<Quest>(crate::EVENT, 19);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -193,18 +216,18 @@ mod tests {
     #[test]
     fn test_p1() {
         let input = "7,7,2\n12,0,4\n15,5,3\n24,1,6\n28,5,5\n40,8,2";
-        assert_eq!(p1(input), 24);
+        assert_eq!(p1(&parse(input).unwrap()), 24);
     }
 
     #[test]
     fn test_p2() {
         let input = "7,7,2\n7,1,3\n12,0,4\n15,5,3\n24,1,6\n28,5,5\n40,3,3\n40,8,2";
-        assert_eq!(p2(input), 22);
+        assert_eq!(p2(&parse(input).unwrap()), 22);
     }
 
     #[test]
     fn test_p3() {
         let input = "7,7,2\n7,1,3\n12,0,4\n15,5,3\n24,1,6\n28,5,5\n40,3,3\n40,8,2";
-        assert_eq!(p3(input), 22);
+        assert_eq!(p3(&parse(input).unwrap()), 22);
     }
 }
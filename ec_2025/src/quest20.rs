@@ -1,11 +1,8 @@
-use std::{collections::VecDeque, time::Instant};
+use std::fmt::Display;
 
-use itertools::Itertools;
-use rustc_hash::FxHashSet;
+use ec_common::{Color, Entry, ParseError, Picture, Solution, parse, search::bfs};
 
-const INPUT_PART1: &str = include_str!("inputs/quest20-1.txt");
-const INPUT_PART2: &str = include_str!("inputs/quest20-2.txt");
-const INPUT_PART3: &str = include_str!("inputs/quest20-3.txt");
+use itertools::Itertools;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 enum CellType {
@@ -43,7 +40,7 @@ impl Cell {
     }
 }
 
-#[derive(Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct Grid {
     cells: Vec<Vec<Cell>>,
     start: (usize, usize),
@@ -51,37 +48,41 @@ struct Grid {
     rotate: bool,
 }
 
-impl From<&str> for Grid {
-    fn from(value: &str) -> Self {
+impl Grid {
+    // Rows are padded with `.` on both sides to make the triangle, so we skip those. What's left
+    // are trampolines (`T`, `S` and `E`) and walls (`#`).
+    fn parse(input: &str) -> parse::Result<Self> {
         let mut start = (0, 0);
         let mut end = (0, 0);
-        let cells = value
-            .trim()
-            .lines()
-            .enumerate()
-            .map(|(row, l)| {
-                l.chars()
-                    .skip(row)
-                    .take_while(|c| *c != '.')
-                    .enumerate()
-                    .zip([Edge::Up, Edge::Down].iter().cycle())
-                    .map(|((col, c), &dir)| {
-                        if c == 'S' {
-                            start = (row, col);
-                        } else if c == 'E' {
-                            end = (row, col);
-                        }
-                        Cell::new(c, dir)
-                    })
-                    .collect()
-            })
-            .collect();
-        Grid {
+        let mut cells = vec![];
+        for (row, line) in input.trim().lines().enumerate() {
+            let mut cells_row = vec![];
+            for (s, &dir) in parse::chars(line)
+                .skip(row)
+                .take_while(|&s| s != ".")
+                .zip([Edge::Up, Edge::Down].iter().cycle())
+            {
+                let c = parse::char(input, s)?;
+                match c {
+                    'S' => start = (row, cells_row.len()),
+                    'E' => end = (row, cells_row.len()),
+                    'T' | '#' => (),
+                    _ => return Err(ParseError::at(input, s, format!("unexpected '{c}'"))),
+                }
+                cells_row.push(Cell::new(c, dir));
+            }
+            cells.push(cells_row);
+        }
+
+        if cells.is_empty() {
+            return Err(ParseError::after(input, input, "there are no trampolines"));
+        }
+        Ok(Grid {
             cells,
             start,
             end,
             rotate: false,
-        }
+        })
     }
 }
 
@@ -118,12 +119,8 @@ impl Grid {
     }
 }
 
-fn parse(input: &str) -> Grid {
-    Grid::from(input)
-}
-
-fn p1(input: &str) -> usize {
-    parse(input).adjacent_trampolines()
+fn p1(grid: &Grid) -> usize {
+    grid.adjacent_trampolines()
 }
 
 impl Grid {
@@ -173,31 +170,18 @@ impl Grid {
     }
 }
 
-fn bfs(grid: &Grid) -> usize {
-    let mut frontier = VecDeque::new();
-    frontier.push_back((grid.start, 0));
-
-    let mut seen = FxHashSet::default();
-    seen.insert(grid.start);
-
-    while let Some(((r, c), cost)) = frontier.pop_front() {
-        if (r, c) == grid.end {
-            return cost;
-        }
-
-        for neighbor in grid.neighbors((r, c)) {
-            if seen.insert(neighbor) {
-                frontier.push_back((neighbor, cost + 1));
-            }
-        }
-    }
-
-    unreachable!()
+fn jumps(grid: &Grid) -> usize {
+    bfs(
+        [grid.start],
+        |&cell| grid.neighbors(cell),
+        |&cell| cell == grid.end,
+    )
+    .goal_distance()
+    .unwrap()
 }
 
-fn p2(input: &str) -> usize {
-    let grid = parse(input);
-    bfs(&grid)
+fn p2(grid: &Grid) -> usize {
+    jumps(grid)
 }
 
 impl Grid {
@@ -213,26 +197,62 @@ impl Grid {
     }
 }
 
-fn p3(input: &str) -> usize {
-    let mut grid = parse(input);
-    grid.rotate = true;
-    bfs(&grid)
+fn p3(grid: &Grid) -> usize {
+    jumps(&Grid {
+        rotate: true,
+        ..grid.clone()
+    })
+}
+
+// The trampolines as they are in the input.
+fn render(input: &str) -> Picture {
+    Picture::from_text(input, |c| match c {
+        'T' => Some(Color::YELLOW),
+        'S' => Some(Color::GREEN),
+        'E' => Some(Color::RED),
+        '#' => Some(Color::GRAY),
+        _ => None,
+    })
 }
 
-fn main() {
-    let now = Instant::now();
-    let solution = p1(INPUT_PART1);
-    println!("p1 {:?} {}", now.elapsed(), solution);
+struct Quest;
+
+impl Solution for Quest {
+    type Part1<'a> = Grid;
+    type Part2<'a> = Grid;
+    type Part3<'a> = Grid;
+
+    fn parse_part1(input: &str) -> anyhow::Result<Self::Part1<'_>> {
+        Ok(Grid::parse(input)?)
+    }
+
+    fn parse_part2(input: &str) -> anyhow::Result<Self::Part2<'_>> {
+        Ok(Grid::parse(input)?)
+    }
+
+    fn parse_part3(input: &str) -> anyhow::Result<Self::Part3<'_>> {
+        Ok(Grid::parse(input)?)
+    }
+
+    fn p1(input: &mut Self::Part1<'_>) -> impl Display {
+        p1(input)
+    }
 
-    let now = Instant::now();
-    let solution = p2(INPUT_PART2);
-    println!("p2 {:?} {}", now.elapsed(), solution);
+    fn p2(input: &mut Self::Part2<'_>) -> impl Display {
+        p2(input)
+    }
+
+    fn p3(input: &mut Self::Part3<'_>) -> impl Display {
+        p3(input)
+    }
 
-    let now = Instant::now();
-    let solution = p3(INPUT_PART3);
-    println!("p3 {:?} {}", now.elapsed(), solution);
+    fn render(_part: u8, input: &str) -> anyhow::Result<Option<Picture>> {
+        Ok(Some(render(input)))
+    }
 }
 
+pub(crate) const ENTRY: Entry = Entry::new::<Quest>(crate::EVENT, 20);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -240,18 +260,26 @@ mod tests {
     #[test]
     fn test_p1() {
         let input = "T#TTT###T##\n.##TT#TT##.\n..T###T#T..\n...##TT#...\n....T##....\n.....#.....";
-        assert_eq!(p1(input), 7);
+        assert_eq!(p1(&Grid::parse(input).unwrap()), 7);
+
+        let error = Grid::parse("T#X").unwrap_err();
+        assert_eq!(error.to_string(), "unexpected 'X' at line 1 col 3");
+        let error = Grid::parse("\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "there are no trampolines at line 2 col 1"
+        );
     }
 
     #[test]
     fn test_p2() {
         let input = "TTTTTTTTTTTTTTTTT\n.TTTT#T#T#TTTTTT.\n..TT#TTTETT#TTT..\n...TT#T#TTT#TT...\n....TTT#T#TTT....\n.....TTTTTT#.....\n......TT#TT......\n.......#TT.......\n........S........";
-        assert_eq!(p2(input), 32);
+        assert_eq!(p2(&Grid::parse(input).unwrap()), 32);
     }
 
     #[test]
     fn test_p3() {
         let input = "T####T#TTT##T##T#T#\n.T#####TTTT##TTT##.\n..TTTT#T###TTTT#T..\n...T#TTT#ETTTT##...\n....#TT##T#T##T....\n.....#TT####T#.....\n......T#TT#T#......\n.......T#TTT.......\n........TT#........\n.........S.........";
-        assert_eq!(p3(input), 23);
+        assert_eq!(p3(&Grid::parse(input).unwrap()), 23);
     }
 }
@@ -0,0 +1,213 @@
+use std::{fs, ops::Range, path::Path, time::Duration};
+
+use anyhow::{Context, bail};
+
+use crate::{
+    point::Point,
+    render::{Image, Picture},
+};
+
+/// Records a [`Picture`] of a simulation at each round in `frames`, to be played back as a GIF or
+/// stepped through as numbered PNGs. Rounds outside of `frames` are never drawn, so looking at a
+/// few rounds of a billion round simulation only costs those rounds.
+pub struct Recorder {
+    frames: Range<usize>,
+    recorded: Vec<(usize, Picture)>,
+    notes: Vec<String>,
+}
+
+impl Recorder {
+    pub fn new(frames: Range<usize>) -> Self {
+        Self {
+            frames,
+            recorded: vec![],
+            notes: vec![],
+        }
+    }
+
+    /// Record the picture of `round` if it's one of the frames. Round 0 is the state before the
+    /// first step.
+    pub fn record(&mut self, round: usize, picture: impl FnOnce() -> Picture) {
+        if self.frames.contains(&round) {
+            self.recorded.push((round, picture()));
+        }
+    }
+
+    /// Whether there's nothing left to record after `round`, so the simulation can stop.
+    pub fn done(&self, round: usize) -> bool {
+        round + 1 >= self.frames.end
+    }
+
+    /// Remember something worth knowing about the recording, like the round a cycle was found.
+    pub fn note(&mut self, note: impl Into<String>) {
+        self.notes.push(note.into());
+    }
+
+    pub fn notes(&self) -> &[String] {
+        &self.notes
+    }
+
+    pub fn len(&self) -> usize {
+        self.recorded.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.recorded.is_empty()
+    }
+
+    /// Each recorded round as pixels. They're all the same size, covering everything drawn in any
+    /// of them.
+    pub fn images(&self) -> Vec<(usize, Image)> {
+        let corners = self
+            .recorded
+            .iter()
+            .flat_map(|(_, picture)| {
+                let (min, size) = picture.bounds();
+                [min, min + Point::new(size.x as isize, size.y as isize)]
+            })
+            .collect::<Vec<_>>();
+        let min = Point::new(
+            corners.iter().map(|p| p.x).min().unwrap_or(0),
+            corners.iter().map(|p| p.y).min().unwrap_or(0),
+        );
+        let max = Point::new(
+            corners.iter().map(|p| p.x).max().unwrap_or(0),
+            corners.iter().map(|p| p.y).max().unwrap_or(0),
+        );
+        let size = Point::new((max.x - min.x) as usize, (max.y - min.y) as usize);
+
+        self.recorded
+            .iter()
+            .map(|(round, picture)| {
+                let mut picture = picture.clone();
+                picture.area(min, size);
+                (*round, picture.rasterize())
+            })
+            .collect()
+    }
+
+    /// Encode as an animated GIF that shows each frame for `delay` and loops forever.
+    pub fn to_gif(&self, delay: Duration) -> anyhow::Result<Vec<u8>> {
+        let images = self.images();
+        let Some((_, first)) = images.first() else {
+            bail!("there aren't any frames");
+        };
+        let width = u16::try_from(first.width).context("too wide for a GIF")?;
+        let height = u16::try_from(first.height).context("too tall for a GIF")?;
+
+        let mut encoder = gif::Encoder::new(vec![], width, height, &[])?;
+        encoder.set_repeat(gif::Repeat::Infinite)?;
+        // GIFs count in hundredths of a second.
+        let delay = (delay.as_millis() / 10).min(u16::MAX as u128) as u16;
+        for (_, image) in &images {
+            let mut frame = gif::Frame::from_rgb_speed(width, height, &image.pixels, 10);
+            frame.delay = delay;
+            encoder.write_frame(&frame)?;
+        }
+        Ok(encoder.into_inner()?)
+    }
+
+    /// Write each frame to `dir` as a PNG named after its round (e.g. `frame-000042.png`).
+    pub fn save_pngs(&self, dir: &Path) -> anyhow::Result<()> {
+        if self.is_empty() {
+            bail!("there aren't any frames");
+        }
+        fs::create_dir_all(dir)?;
+        for (round, image) in self.images() {
+            let path = dir.join(format!("frame-{round:06}.png"));
+            fs::write(&path, image.to_png()?)
+                .with_context(|| format!("couldn't write {}", path.display()))?;
+        }
+        Ok(())
+    }
+
+    /// Save an animated GIF when `path` ends in `.gif`, otherwise numbered PNGs in the `path`
+    /// directory.
+    pub fn save(&self, path: &Path, delay: Duration) -> anyhow::Result<()> {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("gif") => fs::write(path, self.to_gif(delay)?)
+                .with_context(|| format!("couldn't write {}", path.display())),
+            Some(_) => bail!(
+                "can't animate to {}, use .gif or a directory",
+                path.display()
+            ),
+            None => self.save_pngs(path),
+        }
+    }
+}
+
+/// Parse a range of rounds like `100..200` or `100..=199`.
+pub fn parse_frames(s: &str) -> Result<Range<usize>, String> {
+    let bad = || format!("expected a range of rounds like 100..200, not '{s}'");
+    let (start, end) = s.split_once("..").ok_or_else(bad)?;
+    let start = start.parse::<usize>().map_err(|_| bad())?;
+    let end = match end.strip_prefix('=') {
+        Some(end) => end.parse::<usize>().map_err(|_| bad())? + 1,
+        None => end.parse::<usize>().map_err(|_| bad())?,
+    };
+    match start < end {
+        true => Ok(start..end),
+        false => Err(format!("'{s}' doesn't have any rounds in it")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::Color;
+
+    // A dot moving right one cell a round.
+    fn record(frames: Range<usize>) -> Recorder {
+        let mut recorder = Recorder::new(frames);
+        for round in 0.. {
+            recorder.record(round, || {
+                let mut picture = Picture::new();
+                picture.cell(Point::new(round as isize, 0), Color::RED);
+                picture
+            });
+            if recorder.done(round) {
+                break;
+            }
+        }
+        recorder
+    }
+
+    #[test]
+    fn test_record() {
+        let recorder = record(3..6);
+        assert_eq!(recorder.len(), 3);
+
+        // Every frame covers where the dot is in all of them.
+        let images = recorder.images();
+        assert_eq!(
+            images.iter().map(|(r, _)| *r).collect::<Vec<_>>(),
+            [3, 4, 5]
+        );
+        assert!(images.iter().all(|(_, i)| (i.width, i.height) == (24, 8)));
+        assert_eq!(images[1].1.get(12, 4), Color::RED);
+        assert_eq!(images[1].1.get(4, 4), Color::WHITE);
+
+        let gif = recorder.to_gif(Duration::from_millis(100)).unwrap();
+        assert!(gif.starts_with(b"GIF89a"));
+        assert!(Recorder::new(0..1).to_gif(Duration::ZERO).is_err());
+    }
+
+    #[test]
+    fn test_save_pngs() -> anyhow::Result<()> {
+        let dir = std::env::temp_dir().join(format!("ec-frames-{}", std::process::id()));
+        record(0..2).save(&dir, Duration::ZERO)?;
+        assert!(dir.join("frame-000000.png").exists());
+        assert!(dir.join("frame-000001.png").exists());
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_frames() {
+        assert_eq!(parse_frames("100..200"), Ok(100..200));
+        assert_eq!(parse_frames("0..=9"), Ok(0..10));
+        assert!(parse_frames("5..5").is_err());
+        assert!(parse_frames("10").is_err());
+        assert!(parse_frames("a..b").is_err());
+    }
+}
@@ -0,0 +1,175 @@
+//! The assignment problem: give every row of a cost matrix its own column so the total is as small
+//! (or as large) as possible. This is the Hungarian algorithm, which takes `O(rows² × columns)`
+//! rather than trying every way of matching them up.
+
+/// The best way of matching rows to columns and what it adds up to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assignment {
+    pub total: i64,
+    /// `columns[row]` is the column `row` went to.
+    pub columns: Vec<usize>,
+}
+
+impl Assignment {
+    /// Each `(row, column)` pair, in order of row.
+    pub fn pairs(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.columns.iter().copied().enumerate()
+    }
+}
+
+/// The assignment with the smallest total cost. There can be more columns than rows, in which
+/// case some columns aren't used, but not the other way around.
+pub fn min_cost<R: AsRef<[i64]>>(costs: &[R]) -> Assignment {
+    let rows = costs.len();
+    let columns = costs.first().map_or(0, |r| r.as_ref().len());
+    assert!(
+        costs.iter().all(|r| r.as_ref().len() == columns),
+        "every row has to have the same number of columns"
+    );
+    assert!(
+        rows <= columns,
+        "{rows} rows can't each get their own column out of {columns}"
+    );
+    let cost = |r: usize, c: usize| costs[r - 1].as_ref()[c - 1];
+
+    // Rows and columns are counted from 1 here so 0 can be the row being added in each round. The
+    // potentials `u` and `v` never let `cost - u - v` go negative and are 0 for the edges in the
+    // matching, so augmenting along those reduced costs keeps it optimal.
+    let mut u = vec![0; rows + 1];
+    let mut v = vec![0; columns + 1];
+    let mut row_of = vec![0; columns + 1];
+    let mut way = vec![0; columns + 1];
+    for row in 1..=rows {
+        row_of[0] = row;
+        let mut column = 0;
+        let mut min = vec![i64::MAX; columns + 1];
+        let mut used = vec![false; columns + 1];
+        loop {
+            used[column] = true;
+            let r = row_of[column];
+            let (mut delta, mut next) = (i64::MAX, 0);
+            for c in 1..=columns {
+                if !used[c] {
+                    let reduced = cost(r, c) - u[r] - v[c];
+                    if reduced < min[c] {
+                        min[c] = reduced;
+                        way[c] = column;
+                    }
+                    if min[c] < delta {
+                        delta = min[c];
+                        next = c;
+                    }
+                }
+            }
+            for c in 0..=columns {
+                match used[c] {
+                    true => {
+                        u[row_of[c]] += delta;
+                        v[c] -= delta;
+                    }
+                    false => min[c] -= delta,
+                }
+            }
+            column = next;
+            if row_of[column] == 0 {
+                break;
+            }
+        }
+
+        // Flip the matching along the path we found to the free column.
+        while column != 0 {
+            let prev = way[column];
+            row_of[column] = row_of[prev];
+            column = prev;
+        }
+    }
+
+    let mut assigned = vec![0; rows];
+    for c in 1..=columns {
+        if row_of[c] != 0 {
+            assigned[row_of[c] - 1] = c - 1;
+        }
+    }
+    Assignment {
+        total: (0..rows).map(|r| costs[r].as_ref()[assigned[r]]).sum(),
+        columns: assigned,
+    }
+}
+
+/// The assignment with the largest total weight.
+pub fn max_weight<R: AsRef<[i64]>>(weights: &[R]) -> Assignment {
+    let negated = weights
+        .iter()
+        .map(|r| r.as_ref().iter().map(|w| -w).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let assignment = min_cost(&negated);
+    Assignment {
+        total: -assignment.total,
+        ..assignment
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_assignment() {
+        let costs = [[4, 1, 3], [2, 0, 5], [3, 2, 2]];
+        let min = min_cost(&costs);
+        assert_eq!(min.total, 5);
+        assert_eq!(min.columns, [1, 0, 2]);
+
+        let max = max_weight(&costs);
+        assert_eq!(max.total, 11);
+        assert_eq!(max.pairs().collect::<Vec<_>>(), [(0, 0), (1, 2), (2, 1)]);
+
+        // More columns than rows leaves some out.
+        let min = min_cost(&[[5, 9, 1, 7], [2, 8, 1, 6]]);
+        assert_eq!((min.total, min.columns), (3, vec![2, 0]));
+        assert_eq!(min_cost::<[i64; 0]>(&[]).total, 0);
+    }
+
+    // Try every way of giving each row its own column.
+    fn brute(costs: &[Vec<i64>], row: usize, used: &mut Vec<bool>) -> (i64, i64) {
+        if row == costs.len() {
+            return (0, 0);
+        }
+        let (mut min, mut max) = (i64::MAX, i64::MIN);
+        for c in 0..used.len() {
+            if !used[c] {
+                used[c] = true;
+                let (lo, hi) = brute(costs, row + 1, used);
+                used[c] = false;
+                min = min.min(costs[row][c] + lo);
+                max = max.max(costs[row][c] + hi);
+            }
+        }
+        (min, max)
+    }
+
+    proptest! {
+        #[test]
+        fn prop_matches_brute_force(
+            (rows, costs) in (1usize..6).prop_flat_map(|columns| {
+                (1..=columns).prop_flat_map(move |rows| {
+                    let row = prop::collection::vec(-50i64..50, columns);
+                    (Just(rows), prop::collection::vec(row, rows))
+                })
+            })
+        ) {
+            let (min, max) = brute(&costs, 0, &mut vec![false; costs[0].len()]);
+            let assignment = min_cost(&costs);
+            prop_assert_eq!(assignment.total, min);
+            prop_assert_eq!(max_weight(&costs).total, max);
+
+            // Every row gets its own column and the total is really what they add up to.
+            let mut columns = assignment.columns.clone();
+            columns.sort();
+            columns.dedup();
+            prop_assert_eq!(columns.len(), rows);
+            prop_assert_eq!(assignment.pairs().map(|(r, c)| costs[r][c]).sum::<i64>(), min);
+        }
+    }
+}
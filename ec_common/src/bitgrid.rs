@@ -0,0 +1,339 @@
+//! A dense grid of bits, for sets of cells that get merged, compared and counted over and over.
+
+use std::{
+    fmt,
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Sub, SubAssign},
+};
+
+use crate::{grid::Grid, parse, point::Point};
+
+/// A `width` x `height` grid where each cell is either set or not. Cells are bits packed into
+/// `u64` words and each row starts on a new word, so shifting a row or combining two grids is a
+/// plain loop over words that the compiler can vectorize. This beats a hash set or `Vec<bool>`
+/// for things like flood fills and cellular automata.
+///
+/// The bits past the end of each row are always zero, so grids of the same size compare and hash
+/// the same when they have the same cells set.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    words: Vec<u64>,
+    width: usize,
+    height: usize,
+    stride: usize, // words per row
+}
+
+impl BitGrid {
+    /// An empty `width` x `height` grid.
+    pub fn new(width: usize, height: usize) -> Self {
+        let stride = width.div_ceil(64);
+        Self {
+            words: vec![0; stride * height],
+            width,
+            height,
+            stride,
+        }
+    }
+
+    /// Parse the (trimmed) input, setting the cells `f` returns true for. Every line must be the
+    /// same length.
+    pub fn parse_with(input: &str, f: impl Fn(char) -> bool) -> Self {
+        Self::from_grid(&Grid::parse_with(input, f), |&set| set)
+    }
+
+    /// Parse the (trimmed) input, setting the cells `f` returns `Some(true)` for. It's an error
+    /// when a line isn't as wide as the first or `f` returns `None`.
+    pub fn try_parse_with(input: &str, f: impl Fn(char) -> Option<bool>) -> parse::Result<Self> {
+        Ok(Self::from_grid(&Grid::try_parse_with(input, f)?, |&set| {
+            set
+        }))
+    }
+
+    /// A grid the same size as `grid` with the cells set where `f` returns true.
+    pub fn from_grid<T>(grid: &Grid<T>, f: impl Fn(&T) -> bool) -> Self {
+        let mut bits = Self::new(grid.width(), grid.height());
+        for (p, v) in grid.iter() {
+            if f(v) {
+                bits.set(p);
+            }
+        }
+        bits
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        p.within(self.width, self.height)
+    }
+
+    /// The words the cells are packed into, row by row. Cell `(x, y)` is bit `x % 64` of word
+    /// `y * stride + x / 64`.
+    pub fn words(&self) -> &[u64] {
+        &self.words
+    }
+
+    /// How many words each row takes up.
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    // The word `p` is in and its bit in that word.
+    fn bit(&self, p: Point) -> (usize, u64) {
+        assert!(self.contains(p), "{p:?} is outside of the grid");
+        let (x, y) = (p.x as usize, p.y as usize);
+        (y * self.stride + x / 64, 1 << (x % 64))
+    }
+
+    /// Whether `p` is set. Points outside of the grid never are.
+    pub fn get(&self, p: Point) -> bool {
+        self.contains(p) && {
+            let (word, mask) = self.bit(p);
+            self.words[word] & mask != 0
+        }
+    }
+
+    /// Set `p`. Returns false if it already was.
+    pub fn set(&mut self, p: Point) -> bool {
+        let (word, mask) = self.bit(p);
+        let was_set = self.words[word] & mask != 0;
+        self.words[word] |= mask;
+        !was_set
+    }
+
+    /// Clear `p`. Returns false if it already was.
+    pub fn unset(&mut self, p: Point) -> bool {
+        let (word, mask) = self.bit(p);
+        let was_set = self.words[word] & mask != 0;
+        self.words[word] &= !mask;
+        was_set
+    }
+
+    pub fn toggle(&mut self, p: Point) {
+        let (word, mask) = self.bit(p);
+        self.words[word] ^= mask;
+    }
+
+    /// How many cells are set.
+    pub fn count(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    /// Whether no cell is set in both grids, without building their intersection.
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.words.iter().zip(&other.words).all(|(a, b)| a & b == 0)
+    }
+
+    /// Clear every cell.
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    /// Flip every cell.
+    pub fn invert(&mut self) {
+        for w in &mut self.words {
+            *w = !*w;
+        }
+        self.trim();
+    }
+
+    /// Every set cell, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = Point> + '_ {
+        let stride = self.stride.max(1);
+        self.words.iter().enumerate().flat_map(move |(i, &word)| {
+            let (x, y) = ((i % stride) * 64, i / stride);
+            let mut rest = word;
+            std::iter::from_fn(move || {
+                (rest != 0).then(|| {
+                    let bit = rest.trailing_zeros() as usize;
+                    rest &= rest - 1;
+                    Point::new((x + bit) as isize, y as isize)
+                })
+            })
+        })
+    }
+
+    /// The grid moved `dx` cells right and `dy` cells down. Cells moved off the edge are lost and
+    /// the ones uncovered are clear.
+    pub fn shift(&self, dx: isize, dy: isize) -> Self {
+        let mut shifted = Self::new(self.width, self.height);
+        if dx.unsigned_abs() >= self.width || dy.unsigned_abs() >= self.height {
+            return shifted;
+        }
+
+        let (words, bits) = (dx.unsigned_abs() / 64, (dx.unsigned_abs() % 64) as u32);
+        for y in 0..self.height {
+            let Some(from) = y.checked_add_signed(-dy).filter(|&y| y < self.height) else {
+                continue;
+            };
+            let src = &self.words[from * self.stride..(from + 1) * self.stride];
+            let dst = &mut shifted.words[y * self.stride..(y + 1) * self.stride];
+            let word = |i: Option<usize>| i.and_then(|i| src.get(i)).copied().unwrap_or(0);
+
+            for (i, out) in dst.iter_mut().enumerate() {
+                // Moving right moves bits up, pulling in the top of the word before.
+                *out = match dx >= 0 {
+                    true => {
+                        let near = word(i.checked_sub(words));
+                        let far = word(i.checked_sub(words + 1));
+                        near << bits | far.checked_shr(64 - bits).unwrap_or(0)
+                    }
+                    false => {
+                        let near = word(Some(i + words));
+                        let far = word(Some(i + words + 1));
+                        near >> bits | far.checked_shl(64 - bits).unwrap_or(0)
+                    }
+                };
+            }
+        }
+        shifted.trim();
+        shifted
+    }
+
+    // Clear the bits past the end of each row.
+    fn trim(&mut self) {
+        if self.width.is_multiple_of(64) {
+            return;
+        }
+        let mask = (1 << (self.width % 64)) - 1;
+        for row in self.words.chunks_mut(self.stride) {
+            row[self.stride - 1] &= mask;
+        }
+    }
+
+    fn combine(&mut self, other: &Self, f: impl Fn(u64, u64) -> u64) {
+        assert_eq!(
+            (self.width, self.height),
+            (other.width, other.height),
+            "the grids have to be the same size"
+        );
+        for (a, &b) in self.words.iter_mut().zip(&other.words) {
+            *a = f(*a, b);
+        }
+    }
+}
+
+// `a | b` is the union, `a & b` the intersection, `a - b` the difference and `a ^ b` the cells
+// set in only one of them, along with the assigning versions.
+macro_rules! ops {
+    ($($op:ident $method:ident $assign:ident $assign_method:ident |$a:ident, $b:ident| $f:expr;)*) => {$(
+        impl $assign<&BitGrid> for BitGrid {
+            fn $assign_method(&mut self, other: &BitGrid) {
+                self.combine(other, |$a, $b| $f);
+            }
+        }
+
+        impl $op<&BitGrid> for &BitGrid {
+            type Output = BitGrid;
+
+            fn $method(self, other: &BitGrid) -> BitGrid {
+                let mut result = self.clone();
+                result.$assign_method(other);
+                result
+            }
+        }
+    )*};
+}
+
+ops! {
+    BitOr bitor BitOrAssign bitor_assign |a, b| a | b;
+    BitAnd bitand BitAndAssign bitand_assign |a, b| a & b;
+    BitXor bitxor BitXorAssign bitxor_assign |a, b| a ^ b;
+    Sub sub SubAssign sub_assign |a, b| a & !b;
+}
+
+impl fmt::Display for BitGrid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.height as isize {
+            for x in 0..self.width as isize {
+                let c = if self.get(Point::new(x, y)) { '#' } else { '.' };
+                write!(f, "{c}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bits() {
+        let mut bits = BitGrid::parse_with("#..\n.#.\n..#", |c| c == '#');
+        assert_eq!(bits.count(), 3);
+        assert!(bits.get(Point::new(1, 1)));
+        assert!(!bits.get(Point::new(3, 1)));
+        assert!(!bits.set(Point::new(2, 2)));
+        assert!(bits.set(Point::new(2, 0)));
+        assert!(bits.unset(Point::new(0, 0)));
+        assert_eq!(
+            bits.iter().collect::<Vec<_>>(),
+            [Point::new(2, 0), Point::new(1, 1), Point::new(2, 2)]
+        );
+        assert_eq!(bits.to_string(), "..#\n.#.\n..#\n");
+
+        bits.invert();
+        assert_eq!(bits.count(), 6);
+        bits.clear();
+        assert!(bits.is_empty());
+    }
+
+    #[test]
+    fn test_ops() {
+        let a = BitGrid::parse_with("##.\n#..", |c| c == '#');
+        let b = BitGrid::parse_with(".##\n#.#", |c| c == '#');
+        assert_eq!((&a | &b).to_string(), "###\n#.#\n");
+        assert_eq!((&a & &b).to_string(), ".#.\n#..\n");
+        assert_eq!((&a - &b).to_string(), "#..\n...\n");
+        assert_eq!((&a ^ &b).to_string(), "#.#\n..#\n");
+        assert!(!a.is_disjoint(&b));
+        assert!((&a - &b).is_disjoint(&b));
+
+        let mut c = a.clone();
+        c |= &b;
+        c -= &a;
+        assert_eq!(c, &b - &a);
+    }
+
+    #[test]
+    fn test_shift() {
+        // Wide enough that rows take up more than one word.
+        let (width, height) = (150, 4);
+        let mut bits = BitGrid::new(width, height);
+        for p in [(0, 0), (63, 1), (64, 1), (100, 2), (149, 3)] {
+            bits.set(Point::new(p.0, p.1));
+        }
+
+        for (dx, dy) in [
+            (1, 0),
+            (-1, 0),
+            (64, 1),
+            (-65, -1),
+            (37, 2),
+            (-100, 0),
+            (150, 0),
+        ] {
+            let shifted = bits.shift(dx, dy);
+            let expected = bits
+                .iter()
+                .map(|p| p + Point::new(dx, dy))
+                .filter(|&p| p.within(width, height))
+                .collect::<Vec<_>>();
+            assert_eq!(
+                shifted.iter().collect::<Vec<_>>(),
+                expected,
+                "dx={dx} dy={dy}"
+            );
+        }
+    }
+}
@@ -0,0 +1,371 @@
+//! Finding where a sequence of states starts repeating so the state at, or the total value over,
+//! any number of steps can be worked out without running them all.
+//!
+//! There are two ways of finding the cycle. [`floyd`] and [`brent`] only keep a couple of states
+//! around and rerun the sequence to answer questions, which suits cheap steps and big states.
+//! [`Sequence`] and [`CycleDetector`] remember every state in a hash map along with the running
+//! total of their values, which suits expensive steps.
+
+use std::{
+    hash::Hash,
+    ops::{Add, Sub},
+};
+
+use num_bigint::{BigInt, BigUint};
+use rustc_hash::FxHashMap;
+
+/// A value that can be added up over a cycle. Totals over a lot of steps can get big, so this is
+/// implemented for `u128` and the big integers as well as the usual ones.
+pub trait Total: Clone + Add<Output = Self> + Sub<Output = Self> {
+    fn zero() -> Self;
+
+    /// The value added up `n` times.
+    fn times(&self, n: usize) -> Self;
+}
+
+macro_rules! total {
+    ($($t:ty),*) => {$(
+        impl Total for $t {
+            fn zero() -> Self {
+                0
+            }
+
+            fn times(&self, n: usize) -> Self {
+                self * n as $t
+            }
+        }
+    )*};
+}
+
+total!(u32, u64, u128, usize, i32, i64, i128, isize);
+
+impl Total for BigUint {
+    fn zero() -> Self {
+        BigUint::ZERO
+    }
+
+    fn times(&self, n: usize) -> Self {
+        self * n
+    }
+}
+
+impl Total for BigInt {
+    fn zero() -> Self {
+        BigInt::ZERO
+    }
+
+    fn times(&self, n: usize) -> Self {
+        self * n
+    }
+}
+
+/// Where a sequence of states repeats. State 0 is the initial state, the cycle starts at state
+/// `start` and state `start + len` is the same as state `start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub len: usize,
+}
+
+impl Cycle {
+    /// The first state that's the same as state `n`.
+    pub fn index(&self, n: usize) -> usize {
+        match n < self.start {
+            true => n,
+            false => self.start + (n - self.start) % self.len,
+        }
+    }
+
+    /// State `n`, found by rerunning the sequence from `initial` up to its first occurrence.
+    pub fn nth<T: Clone>(&self, initial: &T, mut next: impl FnMut(&T) -> T, n: usize) -> T {
+        (0..self.index(n)).fold(initial.clone(), |state, _| next(&state))
+    }
+
+    /// The total value of states `0..n`, found by rerunning the sequence from `initial` once
+    /// around the cycle.
+    pub fn sum<T: Clone, V: Total>(
+        &self,
+        initial: &T,
+        mut next: impl FnMut(&T) -> T,
+        mut value: impl FnMut(&T) -> V,
+        n: usize,
+    ) -> V {
+        let mut prefix = vec![V::zero()];
+        let mut state = initial.clone();
+        for i in 0..n.min(self.start + self.len) {
+            if i > 0 {
+                state = next(&state);
+            }
+            prefix.push(prefix[i].clone() + value(&state));
+        }
+        self.total(&prefix, n)
+    }
+
+    // The total of states `0..n` from the totals of the states before and in the first cycle.
+    fn total<V: Total>(&self, prefix: &[V], n: usize) -> V {
+        if n < prefix.len() {
+            return prefix[n].clone();
+        }
+        let start = prefix[self.start].clone();
+        let lap = prefix[self.start + self.len].clone() - start.clone();
+        let rest = prefix[self.start + (n - self.start) % self.len].clone() - start.clone();
+        start + lap.times((n - self.start) / self.len) + rest
+    }
+}
+
+/// Floyd's tortoise and hare. Only two states are kept, at the cost of calling `next` about three
+/// times per state. It never returns if the sequence doesn't repeat.
+pub fn floyd<T: Eq + Clone>(initial: &T, mut next: impl FnMut(&T) -> T) -> Cycle {
+    // The hare goes twice as fast, so they meet somewhere in the cycle.
+    let mut tortoise = next(initial);
+    let mut hare = next(&tortoise);
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        let halfway = next(&hare);
+        hare = next(&halfway);
+    }
+
+    // They're now a multiple of the cycle apart, so they meet again where it starts.
+    let mut start = 0;
+    tortoise = initial.clone();
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        start += 1;
+    }
+
+    let mut len = 1;
+    hare = next(&tortoise);
+    while tortoise != hare {
+        hare = next(&hare);
+        len += 1;
+    }
+
+    Cycle { start, len }
+}
+
+/// Brent's algorithm. Like [`floyd`] it only keeps two states, but calls `next` less often.
+pub fn brent<T: Eq + Clone>(initial: &T, mut next: impl FnMut(&T) -> T) -> Cycle {
+    // Find the length by leaving the tortoise at powers of two until the hare comes back to it.
+    let (mut power, mut len) = (1, 1);
+    let mut tortoise = initial.clone();
+    let mut hare = next(initial);
+    while tortoise != hare {
+        if power == len {
+            tortoise = hare.clone();
+            power *= 2;
+            len = 0;
+        }
+        hare = next(&hare);
+        len += 1;
+    }
+
+    // Then start them a cycle apart so they meet where it starts.
+    let mut start = 0;
+    tortoise = initial.clone();
+    hare = (0..len).fold(initial.clone(), |state, _| next(&state));
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        start += 1;
+    }
+
+    Cycle { start, len }
+}
+
+/// A sequence of states run until one repeats, remembering each of them along with the running
+/// total of their values. Any state, or the total over any number of states, can then be looked
+/// up.
+pub struct Sequence<T, V> {
+    states: Vec<T>,
+    prefix: Vec<V>, // prefix[i] is the total of states 0..i
+    cycle: Cycle,
+}
+
+impl<T: Hash + Eq + Clone, V: Total> Sequence<T, V> {
+    /// Run from `initial` until a state repeats. It never returns if none do.
+    pub fn new(initial: T, mut next: impl FnMut(&T) -> T, mut value: impl FnMut(&T) -> V) -> Self {
+        let mut seen = FxHashMap::default();
+        let mut states = vec![];
+        let mut prefix = vec![V::zero()];
+        let mut state = initial;
+        let start = loop {
+            if let Some(&start) = seen.get(&state) {
+                break start;
+            }
+            seen.insert(state.clone(), states.len());
+            prefix.push(prefix[states.len()].clone() + value(&state));
+            let following = next(&state);
+            states.push(state);
+            state = following;
+        };
+
+        let len = states.len() - start;
+        Self {
+            states,
+            prefix,
+            cycle: Cycle { start, len },
+        }
+    }
+
+    pub fn cycle(&self) -> Cycle {
+        self.cycle
+    }
+
+    /// State `n`, where state 0 is the initial state.
+    pub fn state(&self, n: usize) -> &T {
+        &self.states[self.cycle.index(n)]
+    }
+
+    /// The value of state `n`.
+    pub fn value(&self, n: usize) -> V {
+        let i = self.cycle.index(n);
+        self.prefix[i + 1].clone() - self.prefix[i].clone()
+    }
+
+    /// The total value of states `0..n`.
+    pub fn sum(&self, n: usize) -> V {
+        self.cycle.total(&self.prefix, n)
+    }
+}
+
+/// Tracks the states of a simulation and the value produced at each round. Once a state repeats,
+/// the total over all `rounds` can be calculated without running the rest of the simulation.
+/// Unlike [`Sequence`], the simulation stays in charge of stepping, so it can do other things
+/// along the way.
+pub struct CycleDetector<T, V = usize> {
+    seen: FxHashMap<T, usize>, // Previously seen states.
+    prefix_sums: Vec<V>,       // running totals
+    rounds: usize,             // total rounds
+    cycle: Option<Cycle>,
+}
+
+impl<T: Hash + Eq, V: Total> CycleDetector<T, V> {
+    pub fn new(rounds: usize) -> Self {
+        Self {
+            seen: FxHashMap::default(),
+            prefix_sums: vec![V::zero()],
+            rounds,
+            cycle: None,
+        }
+    }
+
+    /// Where the cycle is, once one has been found. Round 0 is the state before the first round.
+    pub fn cycle(&self) -> Option<Cycle> {
+        self.cycle
+    }
+
+    /// Record the `state` after the next round along with the `value` it produced. Returns the
+    /// total over all rounds once it's known.
+    pub fn step(&mut self, state: T, value: V) -> Option<V> {
+        // Update our state.
+        let round = self.prefix_sums.len();
+        self.prefix_sums
+            .push(self.prefix_sums[round - 1].clone() + value);
+
+        // If we've seen this state before, we can now calculate the total.
+        if let Some(start) = self.seen.insert(state, round) {
+            let cycle = Cycle {
+                start,
+                len: round - start,
+            };
+            self.cycle = Some(cycle);
+
+            // The totals are of the states after rounds 1..=i, so they're one state behind.
+            let behind = Cycle {
+                start: start - 1,
+                ..cycle
+            };
+            return Some(behind.total(&self.prefix_sums[..round], self.rounds));
+        }
+
+        // If we don't get a cycle before running out of rounds, it's just the last value.
+        (round >= self.rounds).then(|| self.prefix_sums[self.rounds].clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Run a simple counter mod 5 and use the value as the value for each round.
+    fn total(rounds: usize) -> usize {
+        let mut detector = CycleDetector::new(rounds);
+        let mut state = 0;
+        loop {
+            state = (state + 1) % 5;
+            if let Some(total) = detector.step(state, state) {
+                return total;
+            }
+        }
+    }
+
+    #[test]
+    fn test_step() {
+        let mut detector = CycleDetector::new(100);
+        assert_eq!(detector.step(1, 1), None);
+        assert_eq!(detector.cycle(), None);
+        detector.step(2, 2);
+        detector.step(1, 1);
+        assert_eq!(detector.cycle(), Some(Cycle { start: 1, len: 2 }));
+
+        for rounds in 1..50 {
+            let expected = (1..=rounds).map(|i| i % 5).sum::<usize>();
+            assert_eq!(total(rounds), expected, "rounds={rounds}");
+        }
+    }
+
+    // 3^k mod 20 goes 1, 3, 9, 7, 1, ... and x^2 + 1 mod 255 has a tail before its cycle.
+    fn powers(s: &u64) -> u64 {
+        s * 3 % 20
+    }
+
+    fn squares(s: &u64) -> u64 {
+        (s * s + 1) % 255
+    }
+
+    #[test]
+    fn test_floyd_brent() {
+        assert_eq!(floyd(&1, powers), Cycle { start: 0, len: 4 });
+        assert_eq!(brent(&1, powers), Cycle { start: 0, len: 4 });
+
+        let cycle = Sequence::new(3, squares, |_| 0u64).cycle();
+        assert!(cycle.start > 0);
+        assert_eq!(floyd(&3, squares), cycle);
+        assert_eq!(brent(&3, squares), cycle);
+
+        // Anything past the cycle should be the same as running it out.
+        let mut state = 3;
+        let mut sum = 0u128;
+        for n in 0..200 {
+            assert_eq!(cycle.nth(&3, squares, n), state, "n={n}");
+            assert_eq!(cycle.sum(&3, squares, |&s| s as u128, n), sum, "n={n}");
+            sum += state as u128;
+            state = squares(&state);
+        }
+    }
+
+    #[test]
+    fn test_sequence() {
+        let sequence = Sequence::new(3, squares, |&s| s as u128);
+        let mut state = 3;
+        let mut sum = 0;
+        for n in 0..200 {
+            assert_eq!(*sequence.state(n), state, "n={n}");
+            assert_eq!(sequence.value(n), state as u128);
+            assert_eq!(sequence.sum(n), sum, "n={n}");
+            sum += state as u128;
+            state = squares(&state);
+        }
+    }
+
+    #[test]
+    fn test_big_totals() {
+        // Way more than fits in a u128.
+        let n = usize::MAX;
+        let sequence = Sequence::new(1, powers, |&s| BigUint::from(s) << 100);
+        let laps = BigUint::from(n / 4) * 20u32;
+        let rest = [1u32, 3, 9][..n % 4].iter().sum::<u32>();
+        assert_eq!(sequence.sum(n), (laps + rest) << 100);
+    }
+}
@@ -0,0 +1,45 @@
+//! Formats for `ec run --export`, for handing what a quest worked out to other tools.
+
+use std::path::Path;
+
+use anyhow::bail;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// A Graphviz graph.
+    Dot,
+    Json,
+}
+
+impl Format {
+    /// The format to write `path` in, going by its extension.
+    pub fn from_path(path: &Path) -> anyhow::Result<Self> {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("dot" | "gv") => Ok(Self::Dot),
+            Some("json") => Ok(Self::Json),
+            _ => bail!("can't export to {}, use .dot or .json", path.display()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_path() {
+        assert_eq!(
+            Format::from_path(Path::new("tree.dot")).unwrap(),
+            Format::Dot
+        );
+        assert_eq!(
+            Format::from_path(Path::new("a/tree.json")).unwrap(),
+            Format::Json
+        );
+        let err = Format::from_path(Path::new("tree.txt")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "can't export to tree.txt, use .dot or .json"
+        );
+    }
+}
@@ -0,0 +1,201 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    parse::{self, ParseError},
+    point::Point,
+};
+
+/// A dense, rectangular grid stored row by row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T: Clone> Grid<T> {
+    /// Create a `width` x `height` grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parse each character of the (trimmed) input with `f`. Every line must be the same length,
+    /// use [`Grid::try_parse_with`] for input that might not be.
+    pub fn parse_with(input: &str, f: impl Fn(char) -> T) -> Self {
+        Self::try_parse_with(input, |c| Some(f(c))).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Parse each character of the (trimmed) input with `f`. It's an error when a line isn't as
+    /// wide as the first or `f` doesn't know what to make of a character.
+    pub fn try_parse_with(input: &str, f: impl Fn(char) -> Option<T>) -> parse::Result<Self> {
+        let mut width = 0;
+        let mut height = 0;
+        let mut cells = Vec::with_capacity(input.len());
+        for line in input.trim().lines() {
+            let before = cells.len();
+            for s in parse::chars(line) {
+                let c = parse::char(input, s)?;
+                let cell =
+                    f(c).ok_or_else(|| ParseError::at(input, s, format!("unexpected '{c}'")))?;
+                cells.push(cell);
+            }
+            let len = cells.len() - before;
+            if height == 0 {
+                width = len;
+            }
+            if len != width {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    format!("line is {len} wide but the first is {width}"),
+                ));
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        p.within(self.width, self.height)
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.contains(p)
+            .then(|| &self.cells[p.y as usize * self.width + p.x as usize])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        match self.contains(p) {
+            true => Some(&mut self.cells[p.y as usize * self.width + p.x as usize]),
+            false => None,
+        }
+    }
+
+    /// Iterate over every point in the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> + use<T> {
+        let (width, height) = (self.width as isize, self.height as isize);
+        (0..height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Iterate over every point in the grid along with its value.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Find the first point (row by row) whose value matches `f`.
+    pub fn find(&self, f: impl Fn(&T) -> bool) -> Option<Point> {
+        self.iter().find(|(_, v)| f(v)).map(|(p, _)| p)
+    }
+
+    /// The neighbors of `p` (using the given `deltas`) that are inside the grid.
+    pub fn neighbors<'a>(
+        &self,
+        p: Point,
+        deltas: &'a [Point],
+    ) -> impl Iterator<Item = Point> + use<'a, T> {
+        p.neighbors_within(deltas, self.width, self.height)
+    }
+}
+
+impl From<&str> for Grid<char> {
+    fn from(value: &str) -> Self {
+        Self::parse_with(value, |c| c)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &Self::Output {
+        self.get(p)
+            .unwrap_or_else(|| panic!("{p:?} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut Self::Output {
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("{p:?} is outside of the grid"))
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", row.iter().collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::point::ORTHOGONAL;
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::from("#..\n.S.\n..#\n");
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid.find(|&c| c == 'S'), Some(Point::new(1, 1)));
+        assert_eq!(grid[Point::new(2, 2)], '#');
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.to_string(), "#..\n.S.\n..#\n");
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let mut grid = Grid::parse_with("123\n456", |c| c.to_digit(10).unwrap());
+        assert_eq!(grid.neighbors(Point::new(0, 0), &ORTHOGONAL).count(), 2);
+        assert_eq!(grid.neighbors(Point::new(1, 1), &ORTHOGONAL).count(), 3);
+
+        grid[Point::new(1, 1)] = 0;
+        assert_eq!(grid.iter().map(|(_, v)| v).sum::<u32>(), 16);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ragged() {
+        let _ = Grid::from("###\n##");
+    }
+
+    #[test]
+    fn test_try_parse() {
+        let grid = Grid::try_parse_with("12\n34", |c| c.to_digit(10)).unwrap();
+        assert_eq!(grid[Point::new(1, 1)], 4);
+
+        let err = Grid::try_parse_with("###\n##", Some).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line is 2 wide but the first is 3 at line 2 col 1"
+        );
+        let err = Grid::try_parse_with("12\n3x", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(err.to_string(), "unexpected 'x' at line 2 col 2");
+    }
+}
@@ -0,0 +1,52 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, bail};
+
+use crate::solution::crate_name;
+
+/// Inputs aren't checked in, so they're read when a quest is run. By default they live next to
+/// the quests in `ec_2025/src/inputs` or `story_1/src/inputs`, which is where `helper.nu` puts
+/// them.
+pub fn default_dir(event: u16) -> PathBuf {
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    workspace.join(crate_name(event)).join("src").join("inputs")
+}
+
+/// The file holding the input for the given quest and part (e.g. `quest07-2.txt`).
+pub fn path(dir: &Path, quest: u8, part: u8) -> PathBuf {
+    dir.join(format!("quest{quest:02}-{part}.txt"))
+}
+
+/// Read the input for the given quest and part from `dir`.
+pub fn read(dir: &Path, quest: u8, part: u8) -> anyhow::Result<String> {
+    let path = path(dir, quest, part);
+    match fs::read_to_string(&path) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            bail!("missing input {}", path.display())
+        }
+        r => r.with_context(|| format!("couldn't read input {}", path.display())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_dir() {
+        assert!(default_dir(2025).ends_with("ec_2025/src/inputs"));
+        assert!(default_dir(1).ends_with("story_1/src/inputs"));
+        assert!(path(&default_dir(2), 3, 1).ends_with("story_2/src/inputs/quest03-1.txt"));
+    }
+
+    #[test]
+    fn test_missing() {
+        let dir = std::env::temp_dir().join("ec_common-missing-input");
+        let err = read(&dir, 7, 2).unwrap_err().to_string();
+        assert!(err.starts_with("missing input"), "{err}");
+        assert!(err.ends_with("quest07-2.txt"), "{err}");
+    }
+}
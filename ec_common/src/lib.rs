@@ -0,0 +1,33 @@
+//! Helpers shared by all of the quests: points and their neighbors, dense grids and bit grids,
+//! cycle detection for long running simulations, loading inputs, parse errors that point at the
+//! bad part of the input, drawing grids and paths to PNG or SVG (and animating them as GIFs),
+//! exporting graphs as DOT or JSON, stepping through simulations, searching graphs, union–find,
+//! number theory, the assignment problem and the `Solution` trait the runner uses to find each
+//! quest.
+
+pub mod animation;
+pub mod assignment;
+pub mod bitgrid;
+pub mod cycle;
+pub mod export;
+pub mod grid;
+pub mod input;
+pub mod math;
+pub mod parse;
+pub mod point;
+pub mod render;
+pub mod search;
+pub mod simulation;
+pub mod solution;
+pub mod union_find;
+
+pub use animation::Recorder;
+pub use bitgrid::BitGrid;
+pub use cycle::CycleDetector;
+pub use grid::Grid;
+pub use parse::ParseError;
+pub use point::Point;
+pub use render::{Color, Picture};
+pub use simulation::{Simulation, Stepper};
+pub use solution::{Answer, Entry, Solution};
+pub use union_find::UnionFind;
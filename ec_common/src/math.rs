@@ -0,0 +1,348 @@
+//! Number theory for the modular arithmetic quests: gcds, inverses, powers, the Chinese remainder
+//! theorem and factoring. Anything that can get bigger than an `i128` or `u128` along the way
+//! falls back to big integers.
+
+use std::{
+    fmt,
+    ops::{Div, Mul, Neg, Sub},
+};
+
+use num_bigint::{BigInt, BigUint};
+use thiserror::Error;
+
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+pub fn lcm(a: u64, b: u64) -> u64 {
+    match a == 0 || b == 0 {
+        true => 0,
+        false => a / gcd(a, b) * b,
+    }
+}
+
+/// The gcd of `a` and `b` along with `x` and `y` where `a * x + b * y = gcd`. The gcd is never
+/// negative.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    egcd(a, b)
+}
+
+/// [`extended_gcd`] for big integers.
+pub fn extended_gcd_big(a: &BigInt, b: &BigInt) -> (BigInt, BigInt, BigInt) {
+    egcd(a.clone(), b.clone())
+}
+
+fn egcd<T>(a: T, b: T) -> (T, T, T)
+where
+    T: Clone
+        + PartialOrd
+        + From<i32>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>,
+{
+    let zero = || T::from(0);
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_s, mut s) = (T::from(1), zero());
+    let (mut old_t, mut t) = (zero(), T::from(1));
+    while r != zero() {
+        let q = old_r.clone() / r.clone();
+        (old_r, r) = (r.clone(), old_r - q.clone() * r);
+        (old_s, s) = (s.clone(), old_s - q.clone() * s);
+        (old_t, t) = (t.clone(), old_t - q * t);
+    }
+    match old_r < zero() {
+        true => (-old_r, -old_s, -old_t),
+        false => (old_r, old_s, old_t),
+    }
+}
+
+/// The `x` in `0..m` where `a * x ≡ 1 (mod m)`, if `a` and `m` are coprime.
+pub fn mod_inverse(a: i128, m: i128) -> Option<i128> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// `a * b mod m`, going through big integers if the product doesn't fit in a `u128`.
+pub fn mul_mod(a: u128, b: u128, m: u128) -> u128 {
+    match a.checked_mul(b) {
+        Some(product) => product % m,
+        None => u128::try_from(BigUint::from(a) * b % m).unwrap(),
+    }
+}
+
+/// `base^exp mod m` by repeated squaring.
+pub fn mod_pow(base: u128, mut exp: u128, m: u128) -> u128 {
+    let mut result = 1 % m;
+    let mut base = base % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Why [`crt`] couldn't solve a system of congruences.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum CrtError {
+    #[error("the modulus {0} isn't positive")]
+    BadModulus(BigInt),
+    #[error("no solution: {0}")]
+    NoSolution(Box<Conflict>),
+    #[error("the solution x ≡ {0} (mod {1}) doesn't fit in an i128")]
+    TooBig(BigInt, BigInt),
+}
+
+/// The congruence that can't hold along with the ones before it, which are combined into
+/// `x ≡ so_far (mod lcm)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Conflict {
+    pub remainder: BigInt,
+    pub modulus: BigInt,
+    pub so_far: BigInt,
+    pub lcm: BigInt,
+}
+
+impl fmt::Display for Conflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "x ≡ {} (mod {}) contradicts x ≡ {} (mod {}) from the congruences before it",
+            self.remainder, self.modulus, self.so_far, self.lcm
+        )
+    }
+}
+
+/// Solve the congruences `x ≡ remainder (mod modulus)` for each `(remainder, modulus)`. The
+/// moduli don't have to be coprime. Returns the smallest non-negative `x` along with the lcm of
+/// the moduli, which every other solution differs from it by a multiple of.
+pub fn crt(congruences: &[(i128, i128)]) -> Result<(i128, i128), CrtError> {
+    let big = congruences
+        .iter()
+        .map(|&(r, m)| (BigInt::from(r), BigInt::from(m)))
+        .collect::<Vec<_>>();
+    let (x, lcm) = crt_big(&big)?;
+    match (i128::try_from(&x), i128::try_from(&lcm)) {
+        (Ok(x), Ok(lcm)) => Ok((x, lcm)),
+        _ => Err(CrtError::TooBig(x, lcm)),
+    }
+}
+
+/// [`crt`] for big integers.
+pub fn crt_big(congruences: &[(BigInt, BigInt)]) -> Result<(BigInt, BigInt), CrtError> {
+    let zero = BigInt::ZERO;
+    let (mut x, mut lcm) = (zero.clone(), BigInt::from(1));
+    for (remainder, modulus) in congruences {
+        if *modulus <= zero {
+            return Err(CrtError::BadModulus(modulus.clone()));
+        }
+        let remainder = modulo(remainder, modulus);
+
+        // We need x + lcm * t ≡ remainder (mod modulus), which only works if the gcd divides the
+        // difference.
+        let (g, s, _) = extended_gcd_big(&lcm, modulus);
+        let diff = &remainder - &x;
+        if &diff % &g != zero {
+            return Err(CrtError::NoSolution(Box::new(Conflict {
+                remainder,
+                modulus: modulus.clone(),
+                so_far: x,
+                lcm,
+            })));
+        }
+        let t = modulo(&(diff / &g * s), &(modulus / &g));
+        x += &lcm * t;
+        lcm = lcm / &g * modulus;
+        x = modulo(&x, &lcm);
+    }
+    Ok((x, lcm))
+}
+
+fn modulo(a: &BigInt, m: &BigInt) -> BigInt {
+    ((a % m) + m) % m
+}
+
+/// Whether `n` is prime. This is Miller–Rabin with bases that make it exact for any `u64`.
+pub fn is_prime(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 {
+        return false;
+    }
+    if let Some(&p) = BASES.iter().find(|&&p| n.is_multiple_of(p)) {
+        return n == p;
+    }
+
+    // n - 1 = d * 2^s with d odd.
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    let n = n as u128;
+    BASES.iter().all(|&a| {
+        let mut x = mod_pow(a as u128, d as u128, n);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+        (1..s).any(|_| {
+            x = x * x % n;
+            x == n - 1
+        })
+    })
+}
+
+/// The prime factors of `n` in order, repeated as many times as they divide it. Small factors are
+/// found by trial division and the rest with Pollard's rho. 0 and 1 don't have any.
+pub fn factorize(mut n: u64) -> Vec<u64> {
+    let mut factors = vec![];
+    for p in 2..100 {
+        while n.is_multiple_of(p) && n > 1 {
+            factors.push(p);
+            n /= p;
+        }
+    }
+
+    let mut remaining = vec![n];
+    while let Some(n) = remaining.pop() {
+        match n {
+            0 | 1 => {}
+            n if is_prime(n) => factors.push(n),
+            n => {
+                let d = pollard_rho(n);
+                remaining.extend([d, n / d]);
+            }
+        }
+    }
+    factors.sort();
+    factors
+}
+
+// A non-trivial factor of `n`, which has to be composite. Walks x -> x^2 + c until it cycles mod
+// one of n's factors, trying another c if it happens to cycle mod n itself.
+fn pollard_rho(n: u64) -> u64 {
+    let n128 = n as u128;
+    (1..)
+        .find_map(|c| {
+            let f = |x: u64| ((x as u128 * x as u128 + c) % n128) as u64;
+            let (mut x, mut y, mut d) = (2, 2, 1);
+            while d == 1 {
+                x = f(x);
+                y = f(f(y));
+                d = gcd(x.abs_diff(y), n);
+            }
+            (d != n).then_some(d)
+        })
+        .unwrap()
+}
+
+/// Every divisor of `n` in order, including 1 and `n`.
+pub fn divisors(n: u64) -> Vec<u64> {
+    if n == 0 {
+        return vec![];
+    }
+    let mut divisors = vec![1];
+    let factors = factorize(n);
+    for run in factors.chunk_by(|a, b| a == b) {
+        let current = divisors.len();
+        let mut power = 1;
+        for &p in run {
+            power *= p;
+            for i in 0..current {
+                divisors.push(divisors[i] * power);
+            }
+        }
+    }
+    divisors.sort();
+    divisors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_crt() {
+        // Sunzi's original problem.
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Ok((23, 105)));
+        assert_eq!(crt(&[]), Ok((0, 1)));
+        assert_eq!(crt(&[(3, 4), (5, 6)]), Ok((11, 12)));
+
+        let err = crt(&[(1, 4), (2, 6)]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "no solution: x ≡ 2 (mod 6) contradicts x ≡ 1 (mod 4) from the congruences before it"
+        );
+        assert!(matches!(crt(&[(1, 0)]), Err(CrtError::BadModulus(_))));
+
+        // Big primes whose product doesn't fit.
+        let m = (1 << 100) - 15;
+        assert!(matches!(
+            crt(&[(1, m), (2, m - 2)]),
+            Err(CrtError::TooBig(..))
+        ));
+    }
+
+    #[test]
+    fn test_factorize() {
+        assert_eq!(factorize(0), Vec::<u64>::new());
+        assert_eq!(factorize(1), Vec::<u64>::new());
+        assert_eq!(factorize(360), [2, 2, 2, 3, 3, 5]);
+        assert_eq!(factorize(600851475143), [71, 839, 1471, 6857]);
+        assert_eq!(
+            factorize(1_000_000_007 * 998_244_353),
+            [998_244_353, 1_000_000_007]
+        );
+        assert!(is_prime(18446744073709551557));
+        assert_eq!(divisors(12), [1, 2, 3, 4, 6, 12]);
+        assert_eq!(mod_pow(2, 10, 1000), 24);
+        assert_eq!(mod_inverse(3, 7), Some(5));
+        assert_eq!(mod_inverse(2, 4), None);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_extended_gcd(a in -1_000_000_000i128..1_000_000_000, b in -1_000_000_000i128..1_000_000_000) {
+            let (g, x, y) = extended_gcd(a, b);
+            prop_assert_eq!(a * x + b * y, g);
+            prop_assert_eq!(g as u64, gcd(a.unsigned_abs() as u64, b.unsigned_abs() as u64));
+        }
+
+        #[test]
+        fn prop_crt(x in 0i128..1_000_000, moduli in prop::collection::vec(1i128..200, 1..6)) {
+            // Made from an answer, so there has to be a solution and it has to agree with x.
+            let congruences = moduli.iter().map(|&m| (x % m, m)).collect::<Vec<_>>();
+            let (r, lcm) = crt(&congruences).unwrap();
+            let expected = moduli.iter().fold(1, |l, &m| lcm_i128(l, m));
+            prop_assert_eq!(lcm, expected);
+            prop_assert_eq!(r, x % lcm);
+        }
+
+        #[test]
+        fn prop_crt_no_solution(a in 0i128..30, m in 1i128..30, b in 0i128..30, n in 1i128..30) {
+            let brute = (0..m * n).find(|x| x % m == a % m && x % n == b % n);
+            prop_assert_eq!(crt(&[(a, m), (b, n)]).ok().map(|(x, _)| x), brute);
+        }
+
+        #[test]
+        fn prop_mod_pow(base: u128, exp in 0u128..1_000_000, m in 1u128..) {
+            let expected = BigUint::from(base).modpow(&BigUint::from(exp), &BigUint::from(m));
+            prop_assert_eq!(BigUint::from(mod_pow(base, exp, m)), expected);
+        }
+
+        #[test]
+        fn prop_factorize(n in 1u64..) {
+            let factors = factorize(n);
+            prop_assert_eq!(factors.iter().product::<u64>(), n);
+            prop_assert!(factors.iter().all(|&p| is_prime(p)));
+        }
+    }
+
+    fn lcm_i128(a: i128, b: i128) -> i128 {
+        a / extended_gcd(a, b).0 * b
+    }
+}
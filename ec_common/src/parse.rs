@@ -0,0 +1,128 @@
+use std::str::FromStr;
+
+use thiserror::Error;
+
+/// Something in the input didn't look the way we expected. Lines and columns start at 1.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("{message} at line {line} col {col}")]
+pub struct ParseError {
+    pub message: String,
+    pub line: usize,
+    pub col: usize,
+}
+
+pub type Result<T> = std::result::Result<T, ParseError>;
+
+impl ParseError {
+    /// Point at the start of `at`. It has to be a slice of `input`, which is what `lines`,
+    /// `split`, `trim` and friends all hand back, so the position can be worked out from where it
+    /// sits in memory.
+    pub fn at(input: &str, at: &str, message: impl Into<String>) -> Self {
+        let offset = (at.as_ptr() as usize).checked_sub(input.as_ptr() as usize);
+        debug_assert!(
+            offset.is_some_and(|o| o + at.len() <= input.len()),
+            "{at:?} isn't part of the input"
+        );
+        Self::new(input, offset.unwrap_or(0).min(input.len()), message)
+    }
+
+    /// Point just past the end of `at`. Useful when something is missing.
+    pub fn after(input: &str, at: &str, message: impl Into<String>) -> Self {
+        Self::at(input, &at[at.len()..], message)
+    }
+
+    fn new(input: &str, offset: usize, message: impl Into<String>) -> Self {
+        let before = &input[..offset];
+        let start = before.rfind('\n').map_or(0, |i| i + 1);
+        Self {
+            message: message.into(),
+            line: before.matches('\n').count() + 1,
+            col: before[start..].chars().count() + 1,
+        }
+    }
+}
+
+/// Parse `s` (a slice of `input`) as a number.
+pub fn number<T: FromStr>(input: &str, s: &str) -> Result<T> {
+    s.parse()
+        .map_err(|_| ParseError::at(input, s, format!("bad number '{s}'")))
+}
+
+/// Split `s` (a slice of `input`) into one slice per character so each can be pointed at.
+pub fn chars(s: &str) -> impl Iterator<Item = &str> {
+    s.char_indices().map(|(i, c)| &s[i..i + c.len_utf8()])
+}
+
+/// The first character of `s`.
+pub fn char(input: &str, s: &str) -> Result<char> {
+    s.chars()
+        .next()
+        .ok_or_else(|| ParseError::after(input, s, "expected a character"))
+}
+
+/// The value of the digit at the start of `s`.
+pub fn digit(input: &str, s: &str) -> Result<u32> {
+    let c = char(input, s)?;
+    c.to_digit(10)
+        .ok_or_else(|| ParseError::at(input, s, format!("bad digit '{c}'")))
+}
+
+/// Like [`str::split_once`], but it's an error when `delimiter` isn't there.
+pub fn split_once<'a>(input: &str, s: &'a str, delimiter: &str) -> Result<(&'a str, &'a str)> {
+    s.split_once(delimiter)
+        .ok_or_else(|| ParseError::after(input, s, format!("expected '{delimiter}'")))
+}
+
+/// The next item from `items`, which were split out of `s`. It's an error (pointing at the end of
+/// `s`) when we've run out.
+pub fn next<'a>(
+    input: &str,
+    s: &str,
+    items: &mut impl Iterator<Item = &'a str>,
+    what: &str,
+) -> Result<&'a str> {
+    items
+        .next()
+        .ok_or_else(|| ParseError::after(input, s, format!("missing {what}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position() {
+        let input = "123\n4x6\n789";
+        let line = input.lines().nth(1).unwrap();
+        let err = chars(line)
+            .map(|c| digit(input, c))
+            .collect::<Result<Vec<_>>>()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "bad digit 'x' at line 2 col 2");
+
+        let err = ParseError::after(input, line, "missing");
+        assert_eq!((err.line, err.col), (2, 4));
+        assert_eq!(ParseError::at(input, input, "").col, 1);
+    }
+
+    #[test]
+    fn test_helpers() {
+        let input = "a=12,b=x\nc";
+        let (a, b) = split_once(input, input.lines().next().unwrap(), ",").unwrap();
+        assert_eq!(number::<usize>(input, &a[2..]), Ok(12));
+        assert_eq!(
+            number::<usize>(input, &b[2..]).unwrap_err().to_string(),
+            "bad number 'x' at line 1 col 8"
+        );
+
+        let last = input.lines().nth(1).unwrap();
+        assert_eq!(
+            split_once(input, last, "=").unwrap_err().to_string(),
+            "expected '=' at line 2 col 2"
+        );
+        let mut items = last.split(',');
+        assert_eq!(next(input, last, &mut items, "x"), Ok("c"));
+        assert!(next(input, last, &mut items, "y").is_err());
+        assert_eq!(char(input, last), Ok('c'));
+    }
+}
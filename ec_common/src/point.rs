@@ -0,0 +1,190 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A 2D point. `x` grows to the right (columns) and `y` grows down (rows), which matches how the
+/// puzzle grids are read in.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point<T = isize> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Copy + Neg<Output = T>> Point<T> {
+    // With y pointing down, turning left maps (x, y) to (y, -x) and turning right maps it to
+    // (-y, x).
+    pub fn turn_left(&self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    pub fn turn_right(&self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+}
+
+/// The four orthogonal deltas (up, right, down, left).
+pub const ORTHOGONAL: [Point; 4] = [
+    Point::new(0, -1),
+    Point::new(1, 0),
+    Point::new(0, 1),
+    Point::new(-1, 0),
+];
+
+/// The four diagonal deltas.
+pub const DIAGONAL: [Point; 4] = [
+    Point::new(1, -1),
+    Point::new(1, 1),
+    Point::new(-1, 1),
+    Point::new(-1, -1),
+];
+
+/// All eight surrounding deltas.
+pub const ADJACENT: [Point; 8] = [
+    Point::new(0, -1),
+    Point::new(1, -1),
+    Point::new(1, 0),
+    Point::new(1, 1),
+    Point::new(0, 1),
+    Point::new(-1, 1),
+    Point::new(-1, 0),
+    Point::new(-1, -1),
+];
+
+/// The eight moves a knight can make.
+pub const KNIGHT: [Point; 8] = [
+    Point::new(-2, -1),
+    Point::new(-2, 1),
+    Point::new(2, -1),
+    Point::new(2, 1),
+    Point::new(1, 2),
+    Point::new(-1, 2),
+    Point::new(1, -2),
+    Point::new(-1, -2),
+];
+
+impl Point<isize> {
+    pub fn manhattan(&self, other: &Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    // Check if the point lies inside a `width` x `height` box anchored at the origin.
+    pub fn within(&self, width: usize, height: usize) -> bool {
+        self.x >= 0 && self.y >= 0 && (self.x as usize) < width && (self.y as usize) < height
+    }
+
+    /// Apply each of the given `deltas` to this point.
+    pub fn neighbors(self, deltas: &[Point]) -> impl Iterator<Item = Point> + '_ {
+        deltas.iter().map(move |d| self + *d)
+    }
+
+    /// Same as [`Point::neighbors`] but only yields points within the `width` x `height` box.
+    pub fn neighbors_within(
+        self,
+        deltas: &[Point],
+        width: usize,
+        height: usize,
+    ) -> impl Iterator<Item = Point> + '_ {
+        self.neighbors(deltas)
+            .filter(move |p| p.within(width, height))
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Copy + Add<Output = T>> Add<&Point<T>> for Point<T> {
+    type Output = Self;
+
+    fn add(self, rhs: &Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+// Scalar multiplication.
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ops() {
+        let p = Point::new(1, 2);
+        assert_eq!(p + Point::new(3, 4), Point::new(4, 6));
+        assert_eq!(p - Point::new(3, 4), Point::new(-2, -2));
+        assert_eq!(p * 3, Point::new(3, 6));
+        assert_eq!(-p, Point::new(-1, -2));
+        assert_eq!(p.manhattan(&Point::new(-2, 6)), 7);
+    }
+
+    #[test]
+    fn test_turns() {
+        let up = Point::new(0, -1);
+        assert_eq!(up.turn_right(), Point::new(1, 0));
+        assert_eq!(up.turn_left(), Point::new(-1, 0));
+        assert_eq!(up.turn_left().turn_left().turn_left().turn_left(), up);
+    }
+
+    #[test]
+    fn test_neighbors_within() {
+        let corner = Point::new(0, 0);
+        assert_eq!(corner.neighbors_within(&ORTHOGONAL, 3, 3).count(), 2);
+        assert_eq!(corner.neighbors_within(&ADJACENT, 3, 3).count(), 3);
+        assert_eq!(corner.neighbors_within(&KNIGHT, 3, 3).count(), 2);
+        assert_eq!(
+            Point::new(1, 1).neighbors_within(&DIAGONAL, 3, 3).count(),
+            4
+        );
+    }
+}
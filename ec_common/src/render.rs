@@ -0,0 +1,414 @@
+use std::{fmt::Write as _, fs, path::Path};
+
+use anyhow::{Context, bail};
+
+use crate::{grid::Grid, point::Point};
+
+/// An RGB color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color(pub u8, pub u8, pub u8);
+
+impl Color {
+    pub const BLACK: Color = Color(0, 0, 0);
+    pub const WHITE: Color = Color(0xf5, 0xf5, 0xf5);
+    pub const GRAY: Color = Color(0x80, 0x80, 0x80);
+    pub const RED: Color = Color(0xe6, 0x39, 0x46);
+    pub const ORANGE: Color = Color(0xf4, 0xa2, 0x61);
+    pub const YELLOW: Color = Color(0xe9, 0xc4, 0x6a);
+    pub const GREEN: Color = Color(0x2a, 0x9d, 0x8f);
+    pub const BLUE: Color = Color(0x45, 0x7b, 0x9d);
+
+    /// Blend towards `other`. A `t` of 0 is this color and 1 is `other`.
+    pub fn mix(self, other: Color, t: f64) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+        Color(
+            mix(self.0, other.0),
+            mix(self.1, other.1),
+            mix(self.2, other.2),
+        )
+    }
+
+    fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Shape {
+    Cell(Point, Color),
+    Line {
+        points: Vec<Point>,
+        color: Color,
+        width: f64,
+    },
+    // Nothing is drawn, but the picture covers both corners.
+    Area([Point; 2]),
+}
+
+/// Something to draw. Everything is in grid coordinates: each cell is a 1x1 square with `y`
+/// growing down, and lines go through the middle of the cells they visit. The picture grows to
+/// fit whatever is drawn on it, so negative coordinates are fine.
+#[derive(Debug, Clone)]
+pub struct Picture {
+    pub background: Color,
+    /// How many pixels each cell gets in a PNG (and how big an SVG says it is).
+    pub cell_size: f64,
+    /// The longest side, in pixels. Cells are shrunk (even below a pixel) to stay within it, which
+    /// keeps inputs with huge coordinates from making huge images.
+    pub max_size: usize,
+    shapes: Vec<Shape>,
+}
+
+impl Default for Picture {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE,
+            cell_size: 8.0,
+            max_size: 2048,
+            shapes: vec![],
+        }
+    }
+}
+
+/// Raw pixels, three bytes (RGB) each, row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<u8>,
+}
+
+impl Image {
+    pub fn get(&self, x: usize, y: usize) -> Color {
+        let i = (y * self.width + x) * 3;
+        Color(self.pixels[i], self.pixels[i + 1], self.pixels[i + 2])
+    }
+
+    /// Encode as a PNG.
+    pub fn to_png(&self) -> anyhow::Result<Vec<u8>> {
+        let mut png = vec![];
+        let mut encoder = png::Encoder::new(&mut png, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.pixels)?;
+        writer.finish()?;
+        Ok(png)
+    }
+
+    fn fill(&mut self, x: usize, y: usize, color: Color) {
+        let i = (y * self.width + x) * 3;
+        self.pixels[i..i + 3].copy_from_slice(&[color.0, color.1, color.2]);
+    }
+}
+
+impl Picture {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A cell for each item in `rows`. Items `color` returns `None` for are left as background.
+    pub fn from_rows<T, R: AsRef<[T]>>(rows: &[R], color: impl Fn(&T) -> Option<Color>) -> Self {
+        let mut picture = Self::new();
+        let width = rows.iter().map(|row| row.as_ref().len()).max().unwrap_or(0);
+        picture.area(Point::new(0, 0), Point::new(width, rows.len()));
+        for (y, row) in (0..).zip(rows) {
+            for (x, item) in (0..).zip(row.as_ref()) {
+                if let Some(color) = color(item) {
+                    picture.cell(Point::new(x, y), color);
+                }
+            }
+        }
+        picture
+    }
+
+    /// A cell for each character of (trimmed) text like a quest's input.
+    pub fn from_text(text: &str, color: impl Fn(char) -> Option<Color>) -> Self {
+        let rows = text
+            .trim()
+            .lines()
+            .map(|l| l.chars().collect())
+            .collect::<Vec<Vec<_>>>();
+        Self::from_rows(&rows, |&c| color(c))
+    }
+
+    pub fn from_grid<T>(grid: &Grid<T>, color: impl Fn(&T) -> Option<Color>) -> Self {
+        let mut picture = Self::new();
+        picture.area(Point::new(0, 0), Point::new(grid.width(), grid.height()));
+        for p in grid.points() {
+            if let Some(color) = color(&grid[p]) {
+                picture.cell(p, color);
+            }
+        }
+        picture
+    }
+
+    /// Make sure the picture covers the `width` x `height` cells from `top_left`, even the ones
+    /// that are left as background.
+    pub fn area(&mut self, top_left: Point, size: Point<usize>) -> &mut Self {
+        if size.x > 0 && size.y > 0 {
+            let bottom_right = top_left + Point::new(size.x as isize - 1, size.y as isize - 1);
+            self.shapes.push(Shape::Area([top_left, bottom_right]));
+        }
+        self
+    }
+
+    /// Fill in a single cell.
+    pub fn cell(&mut self, p: Point, color: Color) -> &mut Self {
+        self.shapes.push(Shape::Cell(p, color));
+        self
+    }
+
+    /// Highlight a path (like the route a search found) through the middle of its cells.
+    pub fn path(&mut self, points: impl IntoIterator<Item = Point>, color: Color) -> &mut Self {
+        self.line(points, color, 0.5)
+    }
+
+    /// A line through the given points. `width` is in cells.
+    pub fn line(
+        &mut self,
+        points: impl IntoIterator<Item = Point>,
+        color: Color,
+        width: f64,
+    ) -> &mut Self {
+        self.shapes.push(Shape::Line {
+            points: points.into_iter().collect(),
+            color,
+            width,
+        });
+        self
+    }
+
+    // The top left corner and size, in cells, of everything that's been drawn.
+    pub(crate) fn bounds(&self) -> (Point, Point<usize>) {
+        let mut points = self.shapes.iter().flat_map(|shape| match shape {
+            Shape::Cell(p, _) => std::slice::from_ref(p),
+            Shape::Line { points, .. } => points.as_slice(),
+            Shape::Area(corners) => corners.as_slice(),
+        });
+        let Some(&first) = points.next() else {
+            return (Point::new(0, 0), Point::new(1, 1));
+        };
+        let (min, max) = points.fold((first, first), |(min, max), p| {
+            (
+                Point::new(min.x.min(p.x), min.y.min(p.y)),
+                Point::new(max.x.max(p.x), max.y.max(p.y)),
+            )
+        });
+        let size = Point::new((max.x - min.x + 1) as usize, (max.y - min.y + 1) as usize);
+        (min, size)
+    }
+
+    // Pixels per cell, after shrinking to fit within `max_size`.
+    fn scale(&self) -> f64 {
+        let (_, size) = self.bounds();
+        let longest = size.x.max(size.y) as f64;
+        self.cell_size.min(self.max_size as f64 / longest)
+    }
+
+    /// The size of the image in pixels.
+    pub fn size(&self) -> (usize, usize) {
+        let (_, size) = self.bounds();
+        let scale = self.scale();
+        (
+            ((size.x as f64 * scale).ceil() as usize).max(1),
+            ((size.y as f64 * scale).ceil() as usize).max(1),
+        )
+    }
+
+    pub fn rasterize(&self) -> Image {
+        let (min, _) = self.bounds();
+        let scale = self.scale();
+        let (width, height) = self.size();
+        let mut image = Image {
+            width,
+            height,
+            pixels: [self.background.0, self.background.1, self.background.2]
+                .repeat(width * height),
+        };
+
+        // Where a point (in cells) ends up in the image.
+        let to_pixels = |x: f64, y: f64| ((x - min.x as f64) * scale, (y - min.y as f64) * scale);
+
+        for shape in &self.shapes {
+            match shape {
+                Shape::Cell(p, color) => {
+                    let (x0, y0) = to_pixels(p.x as f64, p.y as f64);
+                    let (x1, y1) = to_pixels(p.x as f64 + 1.0, p.y as f64 + 1.0);
+                    // Cells smaller than a pixel still get one.
+                    let (x1, y1) = (x1.max(x0.floor() + 1.0), y1.max(y0.floor() + 1.0));
+                    for y in y0 as usize..(y1.ceil() as usize).min(height) {
+                        for x in x0 as usize..(x1.ceil() as usize).min(width) {
+                            image.fill(x, y, *color);
+                        }
+                    }
+                }
+                Shape::Line {
+                    points,
+                    color,
+                    width: line_width,
+                } => {
+                    let centers = points
+                        .iter()
+                        .map(|p| to_pixels(p.x as f64 + 0.5, p.y as f64 + 0.5))
+                        .collect::<Vec<_>>();
+                    let radius = (line_width * scale / 2.0).max(0.5);
+                    let segments = centers.windows(2).map(|w| (w[0], w[1]));
+                    // A single point is drawn as a dot.
+                    let segments =
+                        segments.chain((centers.len() == 1).then(|| (centers[0], centers[0])));
+                    for (a, b) in segments {
+                        draw_segment(&mut image, a, b, radius, *color);
+                    }
+                }
+                Shape::Area(_) => {}
+            }
+        }
+        image
+    }
+
+    pub fn to_png(&self) -> anyhow::Result<Vec<u8>> {
+        self.rasterize().to_png()
+    }
+
+    pub fn to_svg(&self) -> String {
+        let (min, size) = self.bounds();
+        let (width, height) = self.size();
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}" width="{width}" height="{height}">"#,
+            min.x, min.y, size.x, size.y
+        );
+        let _ = writeln!(
+            svg,
+            r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+            min.x,
+            min.y,
+            size.x,
+            size.y,
+            self.background.hex()
+        );
+        for shape in &self.shapes {
+            let _ = match shape {
+                Shape::Cell(p, color) => writeln!(
+                    svg,
+                    r#"<rect x="{}" y="{}" width="1" height="1" fill="{}" shape-rendering="crispEdges"/>"#,
+                    p.x,
+                    p.y,
+                    color.hex()
+                ),
+                Shape::Line {
+                    points,
+                    color,
+                    width,
+                } => {
+                    let points = points
+                        .iter()
+                        .map(|p| format!("{},{}", p.x as f64 + 0.5, p.y as f64 + 0.5))
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                    writeln!(
+                        svg,
+                        r#"<polyline points="{points}" fill="none" stroke="{}" stroke-width="{width}" stroke-linecap="round" stroke-linejoin="round"/>"#,
+                        color.hex()
+                    )
+                }
+                Shape::Area(_) => Ok(()),
+            };
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// Write a PNG or SVG, depending on the extension of `path`.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let contents = match path.extension().and_then(|e| e.to_str()) {
+            Some("png") => self.to_png()?,
+            Some("svg") => self.to_svg().into_bytes(),
+            _ => bail!("can't render to {}, use .png or .svg", path.display()),
+        };
+        fs::write(path, contents).with_context(|| format!("couldn't write {}", path.display()))
+    }
+}
+
+// Fill every pixel whose center is within `radius` of the segment from `a` to `b`.
+fn draw_segment(image: &mut Image, a: (f64, f64), b: (f64, f64), radius: f64, color: Color) {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let length = dx * dx + dy * dy;
+
+    let clamp = |v: f64, max: usize| (v.max(0.0) as usize).min(max);
+    let (x0, x1) = (a.0.min(b.0) - radius, a.0.max(b.0) + radius);
+    let (y0, y1) = (a.1.min(b.1) - radius, a.1.max(b.1) + radius);
+    for y in clamp(y0.floor(), image.height)..clamp(y1.ceil(), image.height) {
+        for x in clamp(x0.floor(), image.width)..clamp(x1.ceil(), image.width) {
+            let (px, py) = (x as f64 + 0.5, y as f64 + 0.5);
+            // How far along the segment the closest point is.
+            let t = match length == 0.0 {
+                true => 0.0,
+                false => (((px - a.0) * dx + (py - a.1) * dy) / length).clamp(0.0, 1.0),
+            };
+            let (cx, cy) = (a.0 + t * dx, a.1 + t * dy);
+            if (px - cx).powi(2) + (py - cy).powi(2) <= radius * radius {
+                image.fill(x, y, color);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rasterize() {
+        let mut picture = Picture::from_text("#.\n.#", |c| (c == '#').then_some(Color::BLACK));
+        picture.cell_size = 4.0;
+        picture.path([Point::new(0, 1), Point::new(1, 1)], Color::RED);
+
+        let image = picture.rasterize();
+        assert_eq!((image.width, image.height), (8, 8));
+        assert_eq!(image.get(1, 1), Color::BLACK);
+        assert_eq!(image.get(5, 1), Color::WHITE);
+        // The path is drawn over the bottom right cell.
+        assert_eq!(image.get(6, 6), Color::RED);
+        assert_eq!(image.get(6, 4), Color::BLACK);
+
+        let png = image.to_png().unwrap();
+        let decoder = png::Decoder::new(std::io::Cursor::new(png));
+        let info = decoder.read_info().unwrap().info().clone();
+        assert_eq!((info.width, info.height), (8, 8));
+    }
+
+    #[test]
+    fn test_fit() {
+        // Huge coordinates get shrunk to fit.
+        let mut picture = Picture::new();
+        picture.line(
+            [Point::new(-5000, 0), Point::new(5000, 0)],
+            Color::BLUE,
+            1.0,
+        );
+        let (width, height) = picture.size();
+        assert_eq!(width, picture.max_size);
+        assert_eq!(height, 1);
+        assert_eq!(picture.rasterize().get(width / 2, 0), Color::BLUE);
+    }
+
+    #[test]
+    fn test_svg() {
+        let mut picture = Picture::new();
+        picture
+            .cell(Point::new(-1, 2), Color::GREEN)
+            .path([Point::new(0, 0), Point::new(0, 3)], Color::RED);
+        let svg = picture.to_svg();
+        assert!(svg.starts_with(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="-1 0 2 4" width="16" height="32">"#
+        ));
+        assert!(svg.contains(r##"<rect x="-1" y="2" width="1" height="1" fill="#2a9d8f""##));
+        assert!(svg.contains(r#"<polyline points="0.5,0.5 0.5,3.5""#));
+        assert!(svg.ends_with("</svg>\n"));
+        assert!(picture.save(Path::new("out.gif")).is_err());
+    }
+}
@@ -0,0 +1,350 @@
+//! Breadth first, Dijkstra and A* searches over graphs that only exist as a `successors` function.
+//!
+//! Every search can start from more than one node and runs until `goal` says it's found what it's
+//! looking for, or until there's nothing left to visit when it never does. Either way the
+//! [`Search`] it returns has the distance to and path back from everything it reached.
+
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, VecDeque},
+    fmt,
+    hash::Hash,
+    ops::Add,
+};
+
+use rustc_hash::FxHashMap;
+
+/// How much work a search did, for comparing approaches.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    /// Nodes taken off the frontier and expanded.
+    pub visited: usize,
+    /// Nodes put on the frontier, including ones that were later found to be cheaper another way.
+    pub queued: usize,
+    /// The most nodes waiting on the frontier at once.
+    pub max_frontier: usize,
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "visited {} queued {} max frontier {}",
+            self.visited, self.queued, self.max_frontier
+        )
+    }
+}
+
+/// Everything a search reached, how far away it was and where it was reached from.
+#[derive(Debug, Clone)]
+pub struct Search<N, C> {
+    reached: FxHashMap<N, (C, Option<N>)>,
+    /// The node that satisfied `goal`, if one did.
+    pub goal: Option<N>,
+    pub stats: Stats,
+}
+
+impl<N: Hash + Eq + Clone, C: Copy> Search<N, C> {
+    /// The cost of the cheapest way to `node`, if it was reached.
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.reached.get(node).map(|&(cost, _)| cost)
+    }
+
+    /// The cost of getting to the goal.
+    pub fn goal_distance(&self) -> Option<C> {
+        self.distance(self.goal.as_ref()?)
+    }
+
+    /// The nodes along the cheapest way to `node`, starting from whichever start it came from.
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        let mut path = vec![node.clone()];
+        let mut cur = node;
+        while let Some(prev) = &self.reached.get(cur)?.1 {
+            path.push(prev.clone());
+            cur = prev;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// The path to the goal.
+    pub fn goal_path(&self) -> Option<Vec<N>> {
+        self.path(self.goal.as_ref()?)
+    }
+
+    /// Every node that was reached along with its distance. A search stopped by its goal may not
+    /// have settled the distances of nodes that were only queued.
+    pub fn distances(&self) -> impl Iterator<Item = (&N, C)> {
+        self.reached.iter().map(|(node, &(cost, _))| (node, cost))
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.reached.contains_key(node)
+    }
+
+    /// How many nodes were reached.
+    pub fn len(&self) -> usize {
+        self.reached.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.reached.is_empty()
+    }
+}
+
+/// Breadth first search where every step costs 1.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Search<N, usize>
+where
+    N: Hash + Eq + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search {
+        reached: FxHashMap::default(),
+        goal: None,
+        stats: Stats::default(),
+    };
+    let mut frontier = VecDeque::new();
+    for start in starts {
+        if !search.reached.contains_key(&start) {
+            search.reached.insert(start.clone(), (0, None));
+            frontier.push_back((start, 0));
+        }
+    }
+    search.stats.queued = frontier.len();
+
+    while let Some((node, cost)) = frontier.pop_front() {
+        search.stats.visited += 1;
+        if goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+
+        for next in successors(&node) {
+            if !search.reached.contains_key(&next) {
+                search
+                    .reached
+                    .insert(next.clone(), (cost + 1, Some(node.clone())));
+                frontier.push_back((next, cost + 1));
+                search.stats.queued += 1;
+            }
+        }
+        search.stats.max_frontier = search.stats.max_frontier.max(frontier.len());
+    }
+
+    search
+}
+
+/// Dijkstra's algorithm, for when steps cost different amounts. Costs can't be negative and
+/// `C::default()` has to be zero.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Hash + Eq + Clone,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, successors, |_| C::default(), goal)
+}
+
+/// A*, which is Dijkstra's algorithm that tries nodes closer to the goal first. The `heuristic`
+/// guesses the cost from a node to the goal, and the path found is only the cheapest if it never
+/// guesses too high.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Hash + Eq + Clone,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut search = Search {
+        reached: FxHashMap::default(),
+        goal: None,
+        stats: Stats::default(),
+    };
+    let mut frontier = BinaryHeap::new();
+    for start in starts {
+        if !search.reached.contains_key(&start) {
+            search.reached.insert(start.clone(), (C::default(), None));
+            frontier.push(Queued {
+                priority: heuristic(&start),
+                cost: C::default(),
+                node: start,
+            });
+        }
+    }
+    search.stats.queued = frontier.len();
+
+    while let Some(Queued { cost, node, .. }) = frontier.pop() {
+        // We may have found a cheaper way here since this was queued.
+        if search.reached[&node].0 < cost {
+            continue;
+        }
+        search.stats.visited += 1;
+        if goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+
+        for (next, step) in successors(&node) {
+            let cost = cost + step;
+            if search.reached.get(&next).is_none_or(|&(c, _)| cost < c) {
+                search
+                    .reached
+                    .insert(next.clone(), (cost, Some(node.clone())));
+                frontier.push(Queued {
+                    priority: cost + heuristic(&next),
+                    cost,
+                    node: next,
+                });
+                search.stats.queued += 1;
+            }
+        }
+        search.stats.max_frontier = search.stats.max_frontier.max(frontier.len());
+    }
+
+    search
+}
+
+/// A node at a point in time, for searches where what can be reached changes as time goes on,
+/// like obstacles that move. The same node at different times is a different node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Timed<N> {
+    pub node: N,
+    pub time: usize,
+}
+
+/// Breadth first search where each step takes one unit of time and `successors` is told the time
+/// the step starts at. It stops at `until`, so waiting in place can't go on forever.
+pub fn bfs_timed<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N, usize) -> I,
+    until: usize,
+    mut goal: impl FnMut(&Timed<N>) -> bool,
+) -> Search<Timed<N>, usize>
+where
+    N: Hash + Eq + Clone,
+    I: IntoIterator<Item = N>,
+{
+    bfs(
+        starts.into_iter().map(|node| Timed { node, time: 0 }),
+        |timed: &Timed<N>| {
+            let time = timed.time + 1;
+            let next = match timed.time < until {
+                true => Some(successors(&timed.node, timed.time)),
+                false => None,
+            };
+            next.into_iter()
+                .flatten()
+                .map(move |node| Timed { node, time })
+        },
+        &mut goal,
+    )
+}
+
+// The frontier is a max heap, so this orders by priority backwards to pop the cheapest first.
+struct Queued<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> Ord for Queued<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<N, C: Ord> PartialOrd for Queued<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for Queued<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Queued<N, C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::point::{ORTHOGONAL, Point};
+
+    // A 5x5 room with a wall down the middle that has a gap at the bottom.
+    fn open(p: &Point) -> bool {
+        (0..5).contains(&p.x) && (0..5).contains(&p.y) && (p.x != 2 || p.y == 4)
+    }
+
+    fn moves(p: &Point) -> impl Iterator<Item = Point> + use<> {
+        p.neighbors(&ORTHOGONAL).filter(open)
+    }
+
+    #[test]
+    fn test_bfs() {
+        let end = Point::new(4, 0);
+        let search = bfs([Point::new(0, 0)], moves, |p| *p == end);
+        assert_eq!(search.goal_distance(), Some(12));
+        let path = search.goal_path().unwrap();
+        assert_eq!(path.len(), 13);
+        assert!(path.windows(2).all(|w| w[0].manhattan(&w[1]) == 1));
+        assert!(search.stats.visited <= 22);
+
+        // Without a goal, it reaches everything.
+        let search = bfs([Point::new(0, 0), Point::new(4, 0)], moves, |_| false);
+        assert_eq!(search.len(), 21);
+        assert_eq!(search.goal, None);
+        assert_eq!(search.distance(&Point::new(2, 4)), Some(6));
+        assert_eq!(search.distances().map(|(_, d)| d).max(), Some(6));
+    }
+
+    #[test]
+    fn test_dijkstra_astar() {
+        // Going through the right column costs 10 a step.
+        let weighted = |p: &Point| {
+            moves(p)
+                .map(|n| (n, if n.x == 4 { 10 } else { 1 }))
+                .collect::<Vec<_>>()
+        };
+        let end = Point::new(3, 0);
+        let search = dijkstra([Point::new(0, 0)], weighted, |p| *p == end);
+        assert_eq!(search.goal_distance(), Some(11));
+
+        let guess = |p: &Point| p.manhattan(&end);
+        let search = astar([Point::new(0, 0)], weighted, guess, |p| *p == end);
+        assert_eq!(search.goal_distance(), Some(11));
+        assert_eq!(search.goal_path().unwrap().last(), Some(&end));
+    }
+
+    #[test]
+    fn test_bfs_timed() {
+        // The gap in the wall is only open at time 5, so waiting is part of the path.
+        let successors = |p: &Point, time: usize| {
+            let mut next = moves(p)
+                .filter(|n| n.x != 2 || time == 5)
+                .collect::<Vec<_>>();
+            next.push(*p);
+            next
+        };
+        let end = Point::new(4, 4);
+        let search = bfs_timed([Point::new(0, 4)], successors, 20, |t| t.node == end);
+        assert_eq!(search.goal.map(|t| t.time), Some(8));
+
+        let search = bfs_timed([Point::new(0, 4)], successors, 3, |t| t.node == end);
+        assert_eq!(search.goal, None);
+        assert!(search.distances().all(|(t, d)| t.time == d && d <= 3));
+    }
+}
@@ -0,0 +1,191 @@
+/// A simulation that can be stepped through a round at a time with `ec debug`.
+pub trait Simulation: Clone {
+    /// The current state as text, the way the quest describes it.
+    fn state(&self) -> String;
+
+    /// Run the next round. Returns false when there's nothing left to do, in which case the state
+    /// shouldn't change.
+    fn step(&mut self) -> bool;
+
+    /// A line to show along with the state, like which phase it's in or the answer so far.
+    fn status(&self) -> String {
+        String::new()
+    }
+}
+
+// `Simulation` needs `Clone` for snapshots, so this is the object safe version the stepper holds.
+trait Erased {
+    fn state(&self) -> String;
+    fn step(&mut self) -> bool;
+    fn status(&self) -> String;
+    fn snapshot(&self) -> Box<dyn Erased>;
+}
+
+impl<S: Simulation + 'static> Erased for S {
+    fn state(&self) -> String {
+        Simulation::state(self)
+    }
+
+    fn step(&mut self) -> bool {
+        Simulation::step(self)
+    }
+
+    fn status(&self) -> String {
+        Simulation::status(self)
+    }
+
+    fn snapshot(&self) -> Box<dyn Erased> {
+        Box::new(self.clone())
+    }
+}
+
+/// Steps a [`Simulation`] forwards and backwards. A snapshot is kept every so many rounds, so
+/// stepping back replays from the closest one instead of from the start.
+pub struct Stepper {
+    current: Box<dyn Erased>,
+    round: usize,
+    /// The round where the simulation said it was done, once it's been reached.
+    end: Option<usize>,
+    /// `snapshots[i]` is the state at round `i * interval`.
+    snapshots: Vec<Box<dyn Erased>>,
+    interval: usize,
+}
+
+impl Stepper {
+    pub fn new<S: Simulation + 'static>(simulation: S) -> Self {
+        Self::with_interval(simulation, 64)
+    }
+
+    /// Keep a snapshot every `interval` rounds. Smaller intervals use more memory to step back
+    /// faster.
+    pub fn with_interval<S: Simulation + 'static>(simulation: S, interval: usize) -> Self {
+        let current = Box::new(simulation);
+        Self {
+            snapshots: vec![current.snapshot()],
+            current,
+            round: 0,
+            end: None,
+            interval: interval.max(1),
+        }
+    }
+
+    pub fn round(&self) -> usize {
+        self.round
+    }
+
+    pub fn state(&self) -> String {
+        self.current.state()
+    }
+
+    pub fn status(&self) -> String {
+        self.current.status()
+    }
+
+    /// Whether the simulation has nothing left to do after this round.
+    pub fn finished(&self) -> bool {
+        self.end == Some(self.round)
+    }
+
+    /// Run the next round. Returns false if the simulation had already finished.
+    pub fn forward(&mut self) -> bool {
+        if self.finished() {
+            return false;
+        }
+        if !self.current.step() {
+            self.end = Some(self.round);
+            return false;
+        }
+
+        self.round += 1;
+        if self.round.is_multiple_of(self.interval)
+            && self.snapshots.len() == self.round / self.interval
+        {
+            self.snapshots.push(self.current.snapshot());
+        }
+        true
+    }
+
+    /// Go back to the previous round. Returns false at round 0.
+    pub fn back(&mut self) -> bool {
+        match self.round {
+            0 => false,
+            round => {
+                self.seek(round - 1);
+                true
+            }
+        }
+    }
+
+    /// Go to `round`, or as close as we can get if the simulation finishes before it. Returns the
+    /// round we ended up at.
+    pub fn seek(&mut self, round: usize) -> usize {
+        if round < self.round {
+            // Replay from the latest snapshot at or before it.
+            let snapshot = (round / self.interval).min(self.snapshots.len() - 1);
+            self.current = self.snapshots[snapshot].snapshot();
+            self.round = snapshot * self.interval;
+        }
+        while self.round < round && self.forward() {}
+        self.round
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Counts up to a limit, stepping how many times it's been asked to.
+    #[derive(Clone)]
+    struct Counter {
+        value: usize,
+        limit: usize,
+    }
+
+    impl Simulation for Counter {
+        fn state(&self) -> String {
+            self.value.to_string()
+        }
+
+        fn step(&mut self) -> bool {
+            if self.value == self.limit {
+                return false;
+            }
+            self.value += 1;
+            true
+        }
+
+        fn status(&self) -> String {
+            format!("{} to go", self.limit - self.value)
+        }
+    }
+
+    #[test]
+    fn test_stepper() {
+        let mut stepper = Stepper::with_interval(
+            Counter {
+                value: 0,
+                limit: 10,
+            },
+            3,
+        );
+        assert!(!stepper.back());
+        assert!(stepper.forward());
+        assert_eq!((stepper.round(), stepper.state()), (1, "1".to_string()));
+
+        // Going back replays from a snapshot, which should land on the same state.
+        assert_eq!(stepper.seek(8), 8);
+        assert!(stepper.back());
+        assert_eq!((stepper.round(), stepper.state()), (7, "7".to_string()));
+        assert_eq!(stepper.seek(2), 2);
+        assert_eq!(stepper.state(), "2");
+        assert_eq!(stepper.status(), "8 to go");
+
+        // It stops when the simulation does.
+        assert_eq!(stepper.seek(100), 10);
+        assert!(stepper.finished());
+        assert!(!stepper.forward());
+        assert!(stepper.back());
+        assert!(!stepper.finished());
+        assert_eq!(stepper.state(), "9");
+    }
+}
@@ -0,0 +1,249 @@
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use anyhow::Context;
+
+use crate::{animation::Recorder, export::Format, render::Picture, simulation::Stepper};
+
+/// Each quest implements this so the runner can parse and solve its parts. Every part gets its
+/// own input type because the parts of a quest don't always read their input the same way.
+pub trait Solution {
+    type Part1<'a>;
+    type Part2<'a>;
+    type Part3<'a>;
+
+    fn parse_part1(input: &str) -> anyhow::Result<Self::Part1<'_>>;
+    fn parse_part2(input: &str) -> anyhow::Result<Self::Part2<'_>>;
+    fn parse_part3(input: &str) -> anyhow::Result<Self::Part3<'_>>;
+
+    fn p1(input: &mut Self::Part1<'_>) -> impl Display;
+    fn p2(input: &mut Self::Part2<'_>) -> impl Display;
+    fn p3(input: &mut Self::Part3<'_>) -> impl Display;
+
+    /// Draw the given part for `ec run --render`. Quests opt in when there's something worth
+    /// looking at.
+    fn render(_part: u8, _input: &str) -> anyhow::Result<Option<Picture>> {
+        Ok(None)
+    }
+
+    /// Record the rounds of a simulation for `ec run --animate`. Quests that don't simulate
+    /// anything leave the recorder empty.
+    fn animate(_part: u8, _input: &str, _recorder: &mut Recorder) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Start the given part's simulation for `ec debug` to step through.
+    fn simulate(_part: u8, _input: &str) -> anyhow::Result<Option<Stepper>> {
+        Ok(None)
+    }
+
+    /// Write out what the given part worked out (like a graph it found) for `ec run --export`.
+    /// Quests return `None` for formats they don't have anything to say in.
+    fn export(_part: u8, _input: &str, _format: Format) -> anyhow::Result<Option<String>> {
+        Ok(None)
+    }
+
+    /// Anything the given part's answer rests on a guess about (like which of two equally good
+    /// choices to make), a line each. `ec run` warns about them, or fails with `--strict`.
+    fn guesses(_part: u8, _input: &str) -> anyhow::Result<Vec<String>> {
+        Ok(vec![])
+    }
+}
+
+/// The result of running a single part along with how long parsing and solving took.
+#[derive(Debug, Clone)]
+pub struct Answer {
+    pub value: String,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+impl Answer {
+    pub fn elapsed(&self) -> Duration {
+        self.parse + self.solve
+    }
+}
+
+/// A registered quest. The [`Solution`] is erased so quests from every event can live in the same
+/// list.
+#[derive(Clone, Copy)]
+pub struct Entry {
+    pub event: u16,
+    pub quest: u8,
+    run: fn(u8, &str) -> anyhow::Result<Answer>,
+    render: fn(u8, &str) -> anyhow::Result<Option<Picture>>,
+    animate: fn(u8, &str, &mut Recorder) -> anyhow::Result<()>,
+    simulate: fn(u8, &str) -> anyhow::Result<Option<Stepper>>,
+    export: fn(u8, &str, Format) -> anyhow::Result<Option<String>>,
+    guesses: fn(u8, &str) -> anyhow::Result<Vec<String>>,
+}
+
+impl Entry {
+    pub const fn new<S: Solution>(event: u16, quest: u8) -> Self {
+        Self {
+            event,
+            quest,
+            run: run::<S>,
+            render: S::render,
+            animate: S::animate,
+            simulate: S::simulate,
+            export: S::export,
+            guesses: S::guesses,
+        }
+    }
+
+    /// Parse and solve the given part (1, 2 or 3) of this quest. Errors are prefixed with the
+    /// quest and part (e.g. `quest12 part 2: bad digit 'x' at line 3 col 7`).
+    pub fn run(&self, part: u8, input: &str) -> anyhow::Result<Answer> {
+        match part {
+            1..=3 => (self.run)(part, input)
+                .with_context(|| format!("quest{:02} part {part}", self.quest)),
+            _ => anyhow::bail!("quest{:02} has no part {part}", self.quest),
+        }
+    }
+
+    /// Draw the given part, if the quest knows how.
+    pub fn render(&self, part: u8, input: &str) -> anyhow::Result<Option<Picture>> {
+        (self.render)(part, input).with_context(|| format!("quest{:02} part {part}", self.quest))
+    }
+
+    /// Record the given part's simulation, if it has one.
+    pub fn animate(&self, part: u8, input: &str, recorder: &mut Recorder) -> anyhow::Result<()> {
+        (self.animate)(part, input, recorder)
+            .with_context(|| format!("quest{:02} part {part}", self.quest))
+    }
+
+    /// Start the given part's simulation, if it has one.
+    pub fn simulate(&self, part: u8, input: &str) -> anyhow::Result<Option<Stepper>> {
+        (self.simulate)(part, input).with_context(|| format!("quest{:02} part {part}", self.quest))
+    }
+
+    /// Export the given part in `format`, if the quest has anything to export.
+    pub fn export(&self, part: u8, input: &str, format: Format) -> anyhow::Result<Option<String>> {
+        (self.export)(part, input, format)
+            .with_context(|| format!("quest{:02} part {part}", self.quest))
+    }
+
+    /// What the given part had to guess at, if anything.
+    pub fn guesses(&self, part: u8, input: &str) -> anyhow::Result<Vec<String>> {
+        (self.guesses)(part, input).with_context(|| format!("quest{:02} part {part}", self.quest))
+    }
+}
+
+/// Events are years (2024, 2025, ...) and stories are numbered from 1. This follows the same
+/// convention `helper.nu` uses.
+pub fn event_name(event: u16) -> String {
+    match event < 2000 {
+        true => format!("story {event}"),
+        false => event.to_string(),
+    }
+}
+
+/// The crate holding the quests for an event (e.g. `ec_2025` or `story_1`).
+pub fn crate_name(event: u16) -> String {
+    match event < 2000 {
+        true => format!("story_{event}"),
+        false => format!("ec_{event}"),
+    }
+}
+
+fn run<S: Solution>(part: u8, input: &str) -> anyhow::Result<Answer> {
+    // Each part has its own input type, so we can't just pick functions and share the timing.
+    macro_rules! timed {
+        ($parse:ident, $solve:ident) => {{
+            let now = Instant::now();
+            let mut input = S::$parse(input)?;
+            let parse = now.elapsed();
+
+            let now = Instant::now();
+            let value = S::$solve(&mut input).to_string();
+            Ok(Answer {
+                value,
+                parse,
+                solve: now.elapsed(),
+            })
+        }};
+    }
+
+    match part {
+        1 => timed!(parse_part1, p1),
+        2 => timed!(parse_part2, p2),
+        3 => timed!(parse_part3, p3),
+        _ => anyhow::bail!("part {part} doesn't exist"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Example;
+
+    impl Solution for Example {
+        type Part1<'a> = &'a str;
+        type Part2<'a> = Vec<usize>;
+        type Part3<'a> = Vec<usize>;
+
+        fn parse_part1(input: &str) -> anyhow::Result<Self::Part1<'_>> {
+            Ok(input.trim())
+        }
+
+        fn parse_part2(input: &str) -> anyhow::Result<Self::Part2<'_>> {
+            Ok(input
+                .split(',')
+                .map(|n| n.trim().parse())
+                .collect::<Result<_, _>>()?)
+        }
+
+        fn parse_part3(input: &str) -> anyhow::Result<Self::Part3<'_>> {
+            Self::parse_part2(input)
+        }
+
+        fn p1(input: &mut Self::Part1<'_>) -> impl Display {
+            input.len()
+        }
+
+        fn p2(input: &mut Self::Part2<'_>) -> impl Display {
+            input.iter().sum::<usize>()
+        }
+
+        fn p3(input: &mut Self::Part3<'_>) -> impl Display {
+            input.sort();
+            format!("{input:?}")
+        }
+
+        fn render(part: u8, input: &str) -> anyhow::Result<Option<Picture>> {
+            Ok((part == 1).then(|| Picture::from_text(input, |_| Some(crate::Color::BLACK))))
+        }
+    }
+
+    const ENTRY: Entry = Entry::new::<Example>(2025, 1);
+
+    #[test]
+    fn test_run() -> anyhow::Result<()> {
+        assert_eq!(ENTRY.run(1, " abc ")?.value, "3");
+        assert_eq!(ENTRY.run(2, "1,2,3")?.value, "6");
+        assert_eq!(ENTRY.run(3, "3,1,2")?.value, "[1, 2, 3]");
+        assert!(ENTRY.run(4, "").is_err());
+
+        assert_eq!(ENTRY.render(1, "ab\ncd")?.map(|p| p.size()), Some((16, 16)));
+        assert!(ENTRY.render(2, "1,2")?.is_none());
+        assert!(ENTRY.export(1, "abc", Format::Json)?.is_none());
+        assert!(ENTRY.guesses(1, "abc")?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let entry = Entry::new::<Example>(1, 2);
+        let err = entry.run(2, "1,x").unwrap_err();
+        assert_eq!(
+            format!("{err:#}"),
+            "quest02 part 2: invalid digit found in string"
+        );
+        assert_eq!(event_name(entry.event), "story 1");
+        assert_eq!(crate_name(entry.event), "story_1");
+    }
+}
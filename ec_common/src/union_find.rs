@@ -0,0 +1,306 @@
+//! Union–find (a disjoint-set forest): groups of things that get merged together, where the group
+//! anything is in can be looked up almost instantly.
+
+use std::hash::Hash;
+
+use rustc_hash::FxHashMap;
+
+/// Union–find over `0..n`. Each element starts in its own group. Finding an element's group points
+/// everything on the way straight at the root (path compression) and merging hangs the smaller
+/// group off the larger one (union by size), so both stay close to constant time.
+///
+/// Made [`with_rollback`](Self::with_rollback), unions can be undone. That turns off path
+/// compression, since it changes the forest in ways that can't be cheaply undone, so finds take
+/// up to `O(log n)` instead.
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    components: usize,
+    /// The root that was hung off another root for each union, when rolling back is allowed.
+    /// Unions of elements already in the same group are `None` so each union can be undone.
+    history: Option<Vec<Option<usize>>>,
+}
+
+impl UnionFind {
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            components: n,
+            history: None,
+        }
+    }
+
+    /// A union–find whose unions can be undone with [`undo`](Self::undo) and
+    /// [`rollback`](Self::rollback).
+    pub fn with_rollback(n: usize) -> Self {
+        Self {
+            history: Some(vec![]),
+            ..Self::new(n)
+        }
+    }
+
+    /// How many elements there are.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// How many separate groups there are.
+    pub fn count(&self) -> usize {
+        self.components
+    }
+
+    /// The root of the group `i` is in. Two elements are in the same group when they have the same
+    /// root.
+    pub fn find(&mut self, i: usize) -> usize {
+        let mut root = i;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        if self.history.is_none() {
+            let mut cur = i;
+            while cur != root {
+                cur = std::mem::replace(&mut self.parent[cur], root);
+            }
+        }
+        root
+    }
+
+    /// Merge the groups `i` and `j` are in. Returns false if they were already the same group.
+    pub fn union(&mut self, i: usize, j: usize) -> bool {
+        let (root_i, root_j) = (self.find(i), self.find(j));
+        let merged = (root_i != root_j).then(|| {
+            // Hang the smaller tree off the larger to keep it flat.
+            let (small, large) = match self.size[root_i] < self.size[root_j] {
+                true => (root_i, root_j),
+                false => (root_j, root_i),
+            };
+            self.parent[small] = large;
+            self.size[large] += self.size[small];
+            self.components -= 1;
+            small
+        });
+
+        if let Some(history) = &mut self.history {
+            history.push(merged);
+        }
+        merged.is_some()
+    }
+
+    pub fn same(&mut self, i: usize, j: usize) -> bool {
+        self.find(i) == self.find(j)
+    }
+
+    /// How many elements are in the same group as `i`, including `i`.
+    pub fn size(&mut self, i: usize) -> usize {
+        let root = self.find(i);
+        self.size[root]
+    }
+
+    /// Every group, each sorted and in order of their smallest element.
+    pub fn components(&mut self) -> Vec<Vec<usize>> {
+        let mut groups = FxHashMap::<usize, usize>::default();
+        let mut components: Vec<Vec<usize>> = vec![];
+        for i in 0..self.len() {
+            let root = self.find(i);
+            let group = *groups.entry(root).or_insert_with(|| {
+                components.push(vec![]);
+                components.len() - 1
+            });
+            components[group].push(i);
+        }
+        components
+    }
+
+    /// The biggest group. Ties go to the one with the smallest element.
+    pub fn largest(&mut self) -> Vec<usize> {
+        let mut largest: Vec<usize> = vec![];
+        for component in self.components() {
+            if component.len() > largest.len() {
+                largest = component;
+            }
+        }
+        largest
+    }
+
+    /// Something to [`rollback`](Self::rollback) to later. Always 0 without rolling back.
+    pub fn snapshot(&self) -> usize {
+        self.history.as_ref().map_or(0, |h| h.len())
+    }
+
+    /// Undo the last union. Returns false if there's nothing to undo or rolling back isn't on.
+    pub fn undo(&mut self) -> bool {
+        let Some(merged) = self.history.as_mut().and_then(|h| h.pop()) else {
+            return false;
+        };
+        if let Some(small) = merged {
+            let large = self.parent[small];
+            self.parent[small] = small;
+            self.size[large] -= self.size[small];
+            self.components += 1;
+        }
+        true
+    }
+
+    /// Undo every union since the `snapshot` was taken.
+    pub fn rollback(&mut self, snapshot: usize) {
+        while self.snapshot() > snapshot && self.undo() {}
+    }
+}
+
+/// [`UnionFind`] over anything that can be hashed rather than `0..n`. Elements are added the
+/// first time they're seen.
+#[derive(Debug, Clone)]
+pub struct KeyedUnionFind<K> {
+    sets: UnionFind,
+    ids: FxHashMap<K, usize>,
+    keys: Vec<K>,
+}
+
+impl<K: Hash + Eq + Clone> Default for KeyedUnionFind<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Hash + Eq + Clone> KeyedUnionFind<K> {
+    pub fn new() -> Self {
+        Self {
+            sets: UnionFind::new(0),
+            ids: FxHashMap::default(),
+            keys: vec![],
+        }
+    }
+
+    /// Add `key` in its own group if it's new. Returns its id in the underlying [`UnionFind`].
+    pub fn insert(&mut self, key: K) -> usize {
+        if let Some(&id) = self.ids.get(&key) {
+            return id;
+        }
+        let id = self.keys.len();
+        self.sets.parent.push(id);
+        self.sets.size.push(1);
+        self.sets.components += 1;
+        self.ids.insert(key.clone(), id);
+        self.keys.push(key);
+        id
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    pub fn count(&self) -> usize {
+        self.sets.count()
+    }
+
+    /// Merge the groups of `a` and `b`, adding them if they're new. Returns false if they were
+    /// already the same group.
+    pub fn union(&mut self, a: K, b: K) -> bool {
+        let (a, b) = (self.insert(a), self.insert(b));
+        self.sets.union(a, b)
+    }
+
+    /// Whether `a` and `b` are in the same group. Keys that were never added are only the same as
+    /// themselves.
+    pub fn same(&mut self, a: &K, b: &K) -> bool {
+        match (self.ids.get(a), self.ids.get(b)) {
+            (Some(&a), Some(&b)) => self.sets.same(a, b),
+            _ => a == b,
+        }
+    }
+
+    /// Every group, in the order their first keys were added.
+    pub fn components(&mut self) -> Vec<Vec<K>> {
+        self.sets
+            .components()
+            .into_iter()
+            .map(|c| c.into_iter().map(|id| self.keys[id].clone()).collect())
+            .collect()
+    }
+
+    /// The biggest group. Ties go to the one whose first key was added first.
+    pub fn largest(&mut self) -> Vec<K> {
+        self.sets
+            .largest()
+            .into_iter()
+            .map(|id| self.keys[id].clone())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_union_find() {
+        let mut sets = UnionFind::new(6);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(4, 1));
+        assert!(!sets.union(0, 4));
+        assert!(sets.union(2, 3));
+        assert!(sets.same(0, 4));
+        assert!(!sets.same(0, 2));
+        assert_eq!(sets.count(), 3);
+        assert_eq!(sets.size(4), 3);
+        assert_eq!(sets.components(), [vec![0, 1, 4], vec![2, 3], vec![5]]);
+        assert_eq!(sets.largest(), [0, 1, 4]);
+
+        // Without rolling back, there's nothing to undo.
+        assert!(!sets.undo());
+        assert_eq!(sets.snapshot(), 0);
+    }
+
+    #[test]
+    fn test_rollback() {
+        let mut sets = UnionFind::with_rollback(5);
+        sets.union(0, 1);
+        let snapshot = sets.snapshot();
+        sets.union(1, 2);
+        sets.union(0, 2);
+        sets.union(3, 4);
+        assert_eq!(sets.count(), 2);
+
+        // Undoing the union that did nothing shouldn't change anything.
+        assert!(sets.undo());
+        assert!(sets.undo());
+        assert_eq!(sets.count(), 3);
+        assert!(sets.same(0, 2));
+
+        sets.rollback(snapshot);
+        assert_eq!(sets.components(), [vec![0, 1], vec![2], vec![3], vec![4]]);
+        assert_eq!(sets.size(0), 2);
+        sets.rollback(0);
+        assert_eq!(sets.count(), 5);
+        assert!(!sets.undo());
+    }
+
+    #[test]
+    fn test_keyed() {
+        let mut sets = KeyedUnionFind::new();
+        sets.union("b", "a");
+        sets.union("c", "d");
+        sets.union("a", "e");
+        sets.insert("f");
+        assert_eq!(sets.len(), 6);
+        assert_eq!(sets.count(), 3);
+        assert!(sets.same(&"b", &"e"));
+        assert!(!sets.same(&"b", &"z"));
+        assert_eq!(
+            sets.components(),
+            [vec!["b", "a", "e"], vec!["c", "d"], vec!["f"]]
+        );
+        assert_eq!(sets.largest(), ["b", "a", "e"]);
+    }
+}
@@ -0,0 +1,14 @@
+//! Solutions for [story 1](https://everybody.codes/story/1/quests).
+
+use ec_common::Entry;
+
+mod quest01;
+mod quest02;
+mod quest03;
+
+pub const EVENT: u16 = 1;
+
+/// All of the quests solved so far.
+pub fn quests() -> Vec<Entry> {
+    vec![quest01::ENTRY, quest02::ENTRY, quest03::ENTRY]
+}
@@ -0,0 +1,679 @@
+// Unwrap-gate, LOL
+
+use std::fmt::{Display, Formatter};
+
+use ec_common::{Entry, ParseError, Simulation, Solution, Stepper, export::Format, parse};
+
+use itertools::Itertools;
+use rustc_hash::FxHashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Node {
+    value: usize,
+    symbol: char,
+}
+
+impl Node {
+    // Something like `left=[10,A]`.
+    fn parse(input: &str, s: &str) -> parse::Result<Self> {
+        let (_, n) = parse::split_once(input, s, "=")?;
+        let (value, symbol) = parse::split_once(input, n.trim_matches(['[', ']']), ",")?;
+
+        Ok(Node {
+            value: parse::number(input, value)?,
+            symbol: parse::char(input, symbol)?,
+        })
+    }
+}
+
+impl Display for Node {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{},{}]", self.value, self.symbol)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Instruction {
+    Add(usize, Node, Node),
+    Swap(usize),
+}
+
+impl Instruction {
+    fn parse(input: &str, line: &str) -> parse::Result<Self> {
+        let mut pp = line.split_whitespace();
+        let mut next = |what| parse::next(input, line, &mut pp, what);
+
+        match next("instruction")? {
+            "ADD" => {
+                let (_, id) = parse::split_once(input, next("id")?, "=")?;
+                Ok(Instruction::Add(
+                    parse::number(input, id)?,
+                    Node::parse(input, next("left node")?)?,
+                    Node::parse(input, next("right node")?)?,
+                ))
+            }
+            "SWAP" => Ok(Instruction::Swap(parse::number(input, next("id")?)?)),
+            instruction => Err(ParseError::at(
+                input,
+                instruction,
+                format!("unknown instruction '{instruction}'"),
+            )),
+        }
+    }
+}
+
+// The instruction the way it's written in the input.
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Instruction::Add(id, left, right) => write!(f, "ADD id={id} left={left} right={right}"),
+            Instruction::Swap(id) => write!(f, "SWAP {id}"),
+        }
+    }
+}
+
+type InputPart1<'a> = Vec<Instruction>;
+fn parse_input_part1(input: &'_ str) -> parse::Result<InputPart1<'_>> {
+    input
+        .lines()
+        .map(|l| Instruction::parse(input, l))
+        .collect()
+}
+
+// What `SWAP` does. Part 2 swaps the values of the two nodes with the id, part 3 swaps the nodes
+// along with everything under them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Swap {
+    Values,
+    Branches,
+}
+
+impl Swap {
+    fn for_part(part: u8) -> Self {
+        match part {
+            3 => Swap::Branches,
+            _ => Swap::Values,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct TreeNode {
+    id: usize,
+    node: Node,
+    left: Option<usize>,
+    right: Option<usize>,
+}
+
+const LEFT: usize = 0;
+const RIGHT: usize = 1;
+
+// Where a node hangs from: the root of one of the trees, or under another node.
+#[derive(Debug, Clone, Copy)]
+enum Link {
+    Root(usize),
+    Left(usize),
+    Right(usize),
+}
+
+// What an instruction did, so it can be undone.
+#[derive(Debug, Clone, Copy)]
+enum Change {
+    Added([Link; 2]),
+    Swapped(usize, usize),
+    // A `SWAP` of an id that hasn't been added yet.
+    Nothing,
+}
+
+// Both trees share one arena and link to each other's nodes by index. The two nodes with an id
+// stay in the same two slots whichever trees they get swapped into, so swapping branches is just
+// swapping the slots. Every instruction is recorded so they can be undone.
+#[derive(Debug, Clone)]
+struct TangledTree {
+    nodes: Vec<TreeNode>,
+    roots: [Option<usize>; 2],
+    slots: FxHashMap<usize, (usize, usize)>,
+    swap: Swap,
+    history: Vec<Change>,
+}
+
+impl TangledTree {
+    fn new(swap: Swap) -> Self {
+        Self {
+            nodes: vec![],
+            roots: [None, None],
+            slots: FxHashMap::default(),
+            swap,
+            history: vec![],
+        }
+    }
+
+    // How many instructions have been applied.
+    fn applied(&self) -> usize {
+        self.history.len()
+    }
+
+    fn apply(&mut self, instruction: &Instruction) {
+        let change = match *instruction {
+            Instruction::Add(id, left, right) => {
+                let slot = self.nodes.len();
+                let links = [self.insert(LEFT, id, left), self.insert(RIGHT, id, right)];
+                self.slots.insert(id, (slot, slot + 1));
+                Change::Added(links)
+            }
+            Instruction::Swap(id) => match self.slots.get(&id) {
+                Some(&(a, b)) => {
+                    self.swap_slots(a, b);
+                    Change::Swapped(a, b)
+                }
+                None => Change::Nothing,
+            },
+        };
+        self.history.push(change);
+    }
+
+    // Undo the last `n` instructions (or all of them, if there aren't that many).
+    fn undo(&mut self, n: usize) {
+        for _ in 0..n {
+            match self.history.pop() {
+                Some(Change::Added(links)) => {
+                    for link in links {
+                        *self.link(link) = None;
+                    }
+                    let slot = self.nodes.len() - 2;
+                    self.slots.remove(&self.nodes[slot].id);
+                    self.nodes.truncate(slot);
+                }
+                // Swapping is its own undo.
+                Some(Change::Swapped(a, b)) => self.swap_slots(a, b),
+                Some(Change::Nothing) => {}
+                None => break,
+            }
+        }
+    }
+
+    // Undo or replay `instructions` until the first `index` of them have been applied.
+    fn seek(&mut self, instructions: &[Instruction], index: usize) {
+        let index = index.min(instructions.len());
+        self.undo(self.applied().saturating_sub(index));
+        for instruction in &instructions[self.applied()..index] {
+            self.apply(instruction);
+        }
+    }
+
+    // Add a node to the bottom of one of the trees, smaller values going left.
+    fn insert(&mut self, tree: usize, id: usize, node: Node) -> Link {
+        let mut link = Link::Root(tree);
+        while let Some(cur) = *self.link(link) {
+            link = match node.value <= self.nodes[cur].node.value {
+                true => Link::Left(cur),
+                false => Link::Right(cur),
+            };
+        }
+        *self.link(link) = Some(self.nodes.len());
+        self.nodes.push(TreeNode {
+            id,
+            node,
+            left: None,
+            right: None,
+        });
+        link
+    }
+
+    fn link(&mut self, link: Link) -> &mut Option<usize> {
+        match link {
+            Link::Root(tree) => &mut self.roots[tree],
+            Link::Left(n) => &mut self.nodes[n].left,
+            Link::Right(n) => &mut self.nodes[n].right,
+        }
+    }
+
+    fn swap_slots(&mut self, a: usize, b: usize) {
+        match self.swap {
+            Swap::Values => {
+                let node = self.nodes[a].node;
+                self.nodes[a].node = std::mem::replace(&mut self.nodes[b].node, node);
+            }
+            Swap::Branches => self.nodes.swap(a, b),
+        }
+    }
+
+    // The slots in one of the trees with their depths, each node before the ones under it and
+    // left before right.
+    fn preorder(&self, tree: usize) -> Vec<(usize, usize)> {
+        let mut order = vec![];
+        let mut stack = self.roots[tree]
+            .map(|n| (n, 0))
+            .into_iter()
+            .collect::<Vec<_>>();
+        while let Some((n, depth)) = stack.pop() {
+            order.push((n, depth));
+            let node = &self.nodes[n];
+            stack.extend(node.right.map(|r| (r, depth + 1)));
+            stack.extend(node.left.map(|l| (l, depth + 1)));
+        }
+        order
+    }
+
+    // Every level of one of the trees, along with how tall and lopsided it is.
+    fn report(&self, tree: usize) -> Report {
+        let order = self.preorder(tree);
+        let mut levels: Vec<Level> = vec![];
+        for &(n, depth) in &order {
+            if levels.len() == depth {
+                levels.push(Level::default());
+            }
+            let TreeNode { id, node, .. } = &self.nodes[n];
+            levels[depth].ids.push(*id);
+            levels[depth].values.push(node.value);
+            levels[depth].symbols.push(node.symbol);
+        }
+
+        // Everything under a node comes after it, so going backwards has the heights we need.
+        let mut heights = vec![0; self.nodes.len()];
+        let height = |heights: &[isize], n: Option<usize>| n.map_or(0, |n| heights[n]);
+        for &(n, _) in order.iter().rev() {
+            let TreeNode { left, right, .. } = self.nodes[n];
+            heights[n] = 1 + height(&heights, left).max(height(&heights, right));
+        }
+        let balance = self.roots[tree].map_or(0, |root| {
+            let TreeNode { left, right, .. } = self.nodes[root];
+            height(&heights, left) - height(&heights, right)
+        });
+
+        Report { levels, balance }
+    }
+
+    // The symbols on the widest level of each tree.
+    fn message(&self, tie: Tie) -> String {
+        [LEFT, RIGHT]
+            .iter()
+            .filter_map(|&tree| {
+                let report = self.report(tree);
+                report.widest(tie).map(|l| l.symbols.clone())
+            })
+            .collect()
+    }
+
+    // Both trees as a Graphviz graph, with the edges labeled by which side they go to.
+    fn dot(&self) -> String {
+        let mut dot = String::from("digraph tangled {\n");
+        for (tree, name) in [(LEFT, "left"), (RIGHT, "right")] {
+            dot += &format!("    subgraph cluster_{name} {{\n        label={name};\n");
+            for (n, _) in self.preorder(tree) {
+                let TreeNode {
+                    id,
+                    node,
+                    left,
+                    right,
+                } = &self.nodes[n];
+                dot += &format!("        n{n} [label=\"{id} {node}\"];\n");
+                for (side, child) in [("L", left), ("R", right)] {
+                    if let Some(child) = child {
+                        dot += &format!("        n{n} -> n{child} [label={side}];\n");
+                    }
+                }
+            }
+            dot += "    }\n";
+        }
+        dot + "}\n"
+    }
+
+    fn draw(&self, f: &mut Formatter<'_>, n: usize, prefix: &str) -> std::fmt::Result {
+        let node = &self.nodes[n];
+        let children = [("L", node.left), ("R", node.right)]
+            .into_iter()
+            .filter_map(|(side, child)| child.map(|c| (side, c)))
+            .collect::<Vec<_>>();
+        for (i, &(side, child)) in children.iter().enumerate() {
+            let (branch, indent) = match i + 1 == children.len() {
+                true => ("└──", "    "),
+                false => ("├──", "│   "),
+            };
+            let TreeNode { id, node, .. } = &self.nodes[child];
+            writeln!(f, "{prefix}{branch} {side} {id} {node}")?;
+            self.draw(f, child, &format!("{prefix}{indent}"))?;
+        }
+        Ok(())
+    }
+}
+
+// Both trees drawn sideways, each node like `L 4 [5,B]` for the left child with id 4.
+impl Display for TangledTree {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (tree, name) in [(LEFT, "left"), (RIGHT, "right")] {
+            writeln!(f, "{name}:")?;
+            if let Some(root) = self.roots[tree] {
+                let TreeNode { id, node, .. } = &self.nodes[root];
+                writeln!(f, "{id} {node}")?;
+                self.draw(f, root, "")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// Which level wins when there's more than one with the most nodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tie {
+    Shallowest,
+    Deepest,
+}
+
+// The nodes on one level of a tree, from left to right.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Level {
+    ids: Vec<usize>,
+    values: Vec<usize>,
+    symbols: String,
+}
+
+impl Level {
+    fn width(&self) -> usize {
+        self.ids.len()
+    }
+}
+
+// The shape of one of the trees, from the root down.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Report {
+    levels: Vec<Level>,
+    // How much taller the root's left side is than its right.
+    balance: isize,
+}
+
+impl Report {
+    fn height(&self) -> usize {
+        self.levels.len()
+    }
+
+    // The level with the most nodes.
+    fn widest(&self, tie: Tie) -> Option<&Level> {
+        // `max_by_key` takes the last of the ties.
+        match tie {
+            Tie::Shallowest => self.levels.iter().rev().max_by_key(|l| l.width()),
+            Tie::Deepest => self.levels.iter().max_by_key(|l| l.width()),
+        }
+    }
+}
+
+// A line for each level like `1: 2 wide, BD (ids 4 2, values 5 15)`.
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{} high, balance {}", self.height(), self.balance)?;
+        for (depth, level) in self.levels.iter().enumerate() {
+            writeln!(
+                f,
+                "{depth}: {} wide, {} (ids {}, values {})",
+                level.width(),
+                level.symbols,
+                level.ids.iter().join(" "),
+                level.values.iter().join(" ")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn run(input: &[Instruction], swap: Swap) -> TangledTree {
+    let mut tree = TangledTree::new(swap);
+    for instruction in input {
+        tree.apply(instruction);
+    }
+    tree
+}
+
+fn p1(input: &InputPart1) -> String {
+    run(input, Swap::Values).message(Tie::Shallowest)
+}
+
+fn p2(input: &InputPart2) -> String {
+    p1(input)
+}
+
+fn p3(input: &InputPart3) -> String {
+    run(input, Swap::Branches).message(Tie::Shallowest)
+}
+
+// Steps through the instructions for `ec debug`, showing both trees after each one.
+#[derive(Clone)]
+struct Replay {
+    instructions: Vec<Instruction>,
+    tree: TangledTree,
+}
+
+impl Simulation for Replay {
+    fn state(&self) -> String {
+        let mut state = self.tree.to_string();
+        for (tree, name) in [(LEFT, "left"), (RIGHT, "right")] {
+            state += &format!("\n{name} is {}", self.tree.report(tree));
+        }
+        state
+    }
+
+    fn step(&mut self) -> bool {
+        let applied = self.tree.applied();
+        self.tree.seek(&self.instructions, applied + 1);
+        self.tree.applied() > applied
+    }
+
+    fn status(&self) -> String {
+        let Some(last) = self.tree.applied().checked_sub(1) else {
+            return "nothing added yet".to_string();
+        };
+        let message = self.tree.message(Tie::Shallowest);
+        let mut status = format!("after {} the message is {message}", self.instructions[last]);
+
+        // Point out when it only comes down to which of the widest levels we pick.
+        let deepest = self.tree.message(Tie::Deepest);
+        if deepest != message {
+            status += &format!(" ({deepest} going by the deepest)");
+        }
+        status
+    }
+}
+
+struct Quest;
+
+impl Solution for Quest {
+    type Part1<'a> = InputPart1<'a>;
+    type Part2<'a> = InputPart2<'a>;
+    type Part3<'a> = InputPart3<'a>;
+
+    fn parse_part1(input: &str) -> anyhow::Result<Self::Part1<'_>> {
+        Ok(parse_input_part1(input)?)
+    }
+
+    fn parse_part2(input: &str) -> anyhow::Result<Self::Part2<'_>> {
+        Ok(parse_input_part2(input)?)
+    }
+
+    fn parse_part3(input: &str) -> anyhow::Result<Self::Part3<'_>> {
+        Ok(parse_input_part3(input)?)
+    }
+
+    fn p1(input: &mut Self::Part1<'_>) -> impl Display {
+        p1(input)
+    }
+
+    fn p2(input: &mut Self::Part2<'_>) -> impl Display {
+        p2(input)
+    }
+
+    fn p3(input: &mut Self::Part3<'_>) -> impl Display {
+        p3(input)
+    }
+
+    fn simulate(part: u8, input: &str) -> anyhow::Result<Option<Stepper>> {
+        Ok(Some(Stepper::new(Replay {
+            instructions: parse_input_part1(input)?,
+            tree: TangledTree::new(Swap::for_part(part)),
+        })))
+    }
+
+    fn export(part: u8, input: &str, format: Format) -> anyhow::Result<Option<String>> {
+        let tree = run(&parse_input_part1(input)?, Swap::for_part(part));
+        Ok(match format {
+            Format::Dot => Some(tree.dot()),
+            Format::Json => None,
+        })
+    }
+}
+
+pub(crate) const ENTRY: Entry = Entry::new::<Quest>(crate::EVENT, 2);
+
+type InputPart2<'a> = InputPart1<'a>;
+fn parse_input_part2(input: &'_ str) -> parse::Result<InputPart2<'_>> {
+    parse_input_part1(input)
+}
+
+type InputPart3<'a> = InputPart1<'a>;
+fn parse_input_part3(input: &'_ str) -> parse::Result<InputPart3<'_>> {
+    parse_input_part1(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_p1() -> anyhow::Result<()> {
+        let input = parse_input_part1(
+            "ADD id=1 left=[10,A] right=[30,H]\nADD id=2 left=[15,D] right=[25,I]\nADD id=3 left=[12,F] right=[31,J]\nADD id=4 left=[5,B] right=[27,L]\nADD id=5 left=[3,C] right=[28,M]\nADD id=6 left=[20,G] right=[32,K]\nADD id=7 left=[4,E] right=[21,N]",
+        )?;
+        assert_eq!(p1(&input), "CFGNLK");
+        Ok(())
+    }
+
+    #[test]
+    fn test_p1_big() -> anyhow::Result<()> {
+        let input = parse_input_part1(
+            "ADD id=1 left=[160,E] right=[175,S]\nADD id=2 left=[140,W] right=[224,D]\nADD id=3 left=[122,U] right=[203,F]\nADD id=4 left=[204,N] right=[114,G]\nADD id=5 left=[136,V] right=[256,H]\nADD id=6 left=[147,G] right=[192,O]\nADD id=7 left=[232,I] right=[154,K]\nADD id=8 left=[118,E] right=[125,Y]\nADD id=9 left=[102,A] right=[210,D]\nADD id=10 left=[183,Q] right=[254,E]\nADD id=11 left=[146,E] right=[148,C]\nADD id=12 left=[173,Y] right=[299,S]\nADD id=13 left=[190,B] right=[277,B]\nADD id=14 left=[124,T] right=[142,N]\nADD id=15 left=[153,R] right=[133,M]\nADD id=16 left=[252,D] right=[276,M]\nADD id=17 left=[258,I] right=[245,P]\nADD id=18 left=[117,O] right=[283,!]\nADD id=19 left=[212,O] right=[127,R]\nADD id=20 left=[278,A] right=[169,C]",
+        )?;
+        assert_eq!(p1(&input), "EVERYBODYCODES");
+        Ok(())
+    }
+
+    #[test]
+    fn test_p2() -> anyhow::Result<()> {
+        let input = parse_input_part2(
+            "ADD id=1 left=[10,A] right=[30,H]\nADD id=2 left=[15,D] right=[25,I]\nADD id=3 left=[12,F] right=[31,J]\nADD id=4 left=[5,B] right=[27,L]\nADD id=5 left=[3,C] right=[28,M]\nSWAP 1\nSWAP 5\nADD id=6 left=[20,G] right=[32,K]\nADD id=7 left=[4,E] right=[21,N]",
+        )?;
+        assert_eq!(p2(&input), "MGFLNK");
+        Ok(())
+    }
+
+    #[test]
+    fn test_p3() -> anyhow::Result<()> {
+        let input = parse_input_part3(
+            "ADD id=1 left=[10,A] right=[30,H]\nADD id=2 left=[15,D] right=[25,I]\nADD id=3 left=[12,F] right=[31,J]\nADD id=4 left=[5,B] right=[27,L]\nADD id=5 left=[3,C] right=[28,M]\nSWAP 1\nSWAP 5\nADD id=6 left=[20,G] right=[32,K]\nADD id=7 left=[4,E] right=[21,N]\nSWAP 2",
+        )?;
+        assert_eq!(p3(&input), "DJMGL");
+        Ok(())
+    }
+
+    #[test]
+    fn test_undo() -> anyhow::Result<()> {
+        let input = parse_input_part3(
+            "ADD id=1 left=[10,A] right=[30,H]\nADD id=2 left=[15,D] right=[25,I]\nADD id=3 left=[12,F] right=[31,J]\nADD id=4 left=[5,B] right=[27,L]\nADD id=5 left=[3,C] right=[28,M]\nSWAP 1\nSWAP 5\nSWAP 9\nADD id=6 left=[20,G] right=[32,K]\nADD id=7 left=[4,E] right=[21,N]\nSWAP 2",
+        )?;
+        for swap in [Swap::Values, Swap::Branches] {
+            let mut tree = run(&input, swap);
+            assert_eq!(tree.applied(), input.len());
+
+            // Going back to any point has to look just like getting there from the start.
+            for index in [7, 3, 10, 0, 11, 5] {
+                tree.seek(&input, index);
+                let fresh = run(&input[..index], swap);
+                assert_eq!(tree.to_string(), fresh.to_string(), "{swap:?} at {index}");
+                assert_eq!(tree.report(LEFT), fresh.report(LEFT));
+                assert_eq!(tree.report(RIGHT), fresh.report(RIGHT));
+            }
+
+            tree.undo(100);
+            assert_eq!(tree.applied(), 0);
+            assert!(tree.nodes.is_empty() && tree.slots.is_empty());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_dump() -> anyhow::Result<()> {
+        let input = parse_input_part3(
+            "ADD id=1 left=[10,A] right=[30,H]\nADD id=2 left=[15,D] right=[25,I]\nADD id=3 left=[12,F] right=[31,J]\nADD id=4 left=[5,B] right=[27,L]\nSWAP 1",
+        )?;
+        let tree = run(&input, Swap::Branches);
+        assert_eq!(
+            tree.to_string(),
+            "left:
+1 [30,H]
+├── L 2 [25,I]
+│   └── R 4 [27,L]
+└── R 3 [31,J]
+right:
+1 [10,A]
+├── L 4 [5,B]
+└── R 2 [15,D]
+    └── L 3 [12,F]
+"
+        );
+        assert!(
+            tree.dot()
+                .contains("n0 [label=\"1 [30,H]\"];\n        n0 -> n3 [label=L];")
+        );
+
+        let mut stepper = ENTRY.simulate(3, &input.iter().join("\n"))?.unwrap();
+        stepper.seek(4);
+        assert_eq!(
+            stepper.status(),
+            "after ADD id=4 left=[5,B] right=[27,L] the message is BDIJ"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_report() -> anyhow::Result<()> {
+        let input = parse_input_part1(
+            "ADD id=1 left=[10,A] right=[30,H]\nADD id=2 left=[15,D] right=[25,I]\nADD id=3 left=[12,F] right=[31,J]\nADD id=4 left=[5,B] right=[27,L]\nADD id=5 left=[3,C] right=[28,M]\nADD id=6 left=[20,G] right=[32,K]\nADD id=7 left=[4,E] right=[21,N]",
+        )?;
+        let tree = run(&input, Swap::Values);
+        assert_eq!(
+            tree.report(LEFT).to_string(),
+            "4 high, balance 1
+0: 1 wide, A (ids 1, values 10)
+1: 2 wide, BD (ids 4 2, values 5 15)
+2: 3 wide, CFG (ids 5 3 6, values 3 12 20)
+3: 1 wide, E (ids 7, values 4)
+"
+        );
+        let right = tree.report(RIGHT);
+        assert_eq!((right.height(), right.balance), (4, 1));
+        assert_eq!(right.widest(Tie::Deepest).unwrap().ids, [7, 4, 6]);
+
+        // Two levels are as wide as each other in both trees.
+        let input = parse_input_part1(
+            "ADD id=1 left=[10,A] right=[10,H]\nADD id=2 left=[5,B] right=[5,I]\nADD id=3 left=[15,C] right=[15,J]\nADD id=4 left=[3,D] right=[3,K]\nADD id=5 left=[7,E] right=[7,L]",
+        )?;
+        let tree = run(&input, Swap::Values);
+        assert_eq!(tree.message(Tie::Shallowest), "BCIJ");
+        assert_eq!(tree.message(Tie::Deepest), "DEKL");
+
+        let mut stepper = ENTRY.simulate(1, &input.iter().join("\n"))?.unwrap();
+        stepper.seek(5);
+        assert_eq!(
+            stepper.status(),
+            "after ADD id=5 left=[7,E] right=[7,L] the message is BCIJ (DEKL going by the deepest)"
+        );
+        assert!(stepper.state().contains("\nright is 3 high, balance 1\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_p3_2() -> anyhow::Result<()> {
+        let input = parse_input_part3(
+            "ADD id=1 left=[10,A] right=[30,H]\nADD id=2 left=[15,D] right=[25,I]\nADD id=3 left=[12,F] right=[31,J]\nADD id=4 left=[5,B] right=[27,L]\nADD id=5 left=[3,C] right=[28,M]\nSWAP 1\nSWAP 5\nADD id=6 left=[20,G] right=[32,K]\nADD id=7 left=[4,E] right=[21,N]\nSWAP 2\nSWAP 5",
+        )?;
+        assert_eq!(p3(&input), "DJCGL");
+        Ok(())
+    }
+}
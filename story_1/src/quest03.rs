@@ -0,0 +1,161 @@
+use std::fmt::Display;
+
+use ec_common::{Entry, Solution, math::crt, parse};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Point {
+    x: usize,
+    y: usize,
+}
+
+impl Point {
+    fn value(&self) -> usize {
+        self.x + 100 * self.y
+    }
+
+    // Determine which disc it's on.
+    fn disc(&self) -> usize {
+        let mut cur = *self;
+        while cur.y != 1 {
+            cur.x += 1;
+            cur.y -= 1;
+        }
+        cur.x
+    }
+}
+
+impl Point {
+    // Something like `x=1 y=2`.
+    fn parse(input: &str, line: &str) -> parse::Result<Self> {
+        let (l, r) = parse::split_once(input, line, " ")?;
+        let (_, x) = parse::split_once(input, l, "=")?;
+        let (_, y) = parse::split_once(input, r, "=")?;
+
+        Ok(Point {
+            x: parse::number(input, x)?,
+            y: parse::number(input, y)?,
+        })
+    }
+}
+
+type InputPart1<'a> = Vec<Point>;
+fn parse_input_part1(input: &'_ str) -> parse::Result<InputPart1<'_>> {
+    input.lines().map(|l| Point::parse(input, l)).collect()
+}
+
+fn p1(input: &mut InputPart1) -> usize {
+    for _ in 0..100 {
+        for p in input.iter_mut() {
+            if p.y == 1 {
+                p.y = p.x;
+                p.x = 1;
+            } else {
+                p.x += 1;
+                p.y -= 1;
+            }
+        }
+    }
+
+    input.iter().map(|p| p.value()).sum::<usize>()
+}
+
+fn p2(input: &mut InputPart2) -> usize {
+    // For each snail, we have a congruence t ≡ y - 1 (mod m)
+    // where m is the disc size (x + y - 1).
+    // https://cp-algorithms.com/algebra/chinese-remainder-theorem.html
+    let pairs = input
+        .iter()
+        .map(|p| {
+            let m = p.disc() as i128;
+            let r = p.y as i128 - 1;
+            (r, m)
+        })
+        .collect::<Vec<_>>();
+    // 0 when there's no day they all line up.
+    crt(&pairs).map_or(0, |(r, _)| r as usize)
+}
+
+fn p3(input: &mut InputPart3) -> usize {
+    p2(input)
+}
+
+struct Quest;
+
+impl Solution for Quest {
+    type Part1<'a> = InputPart1<'a>;
+    type Part2<'a> = InputPart2<'a>;
+    type Part3<'a> = InputPart3<'a>;
+
+    fn parse_part1(input: &str) -> anyhow::Result<Self::Part1<'_>> {
+        Ok(parse_input_part1(input)?)
+    }
+
+    fn parse_part2(input: &str) -> anyhow::Result<Self::Part2<'_>> {
+        Ok(parse_input_part2(input)?)
+    }
+
+    fn parse_part3(input: &str) -> anyhow::Result<Self::Part3<'_>> {
+        Ok(parse_input_part3(input)?)
+    }
+
+    fn p1(input: &mut Self::Part1<'_>) -> impl Display {
+        p1(input)
+    }
+
+    fn p2(input: &mut Self::Part2<'_>) -> impl Display {
+        p2(input)
+    }
+
+    fn p3(input: &mut Self::Part3<'_>) -> impl Display {
+        p3(input)
+    }
+}
+
+pub(crate) const ENTRY: Entry = Entry::new::<Quest>(crate::EVENT, 3);
+
+type InputPart2<'a> = InputPart1<'a>;
+fn parse_input_part2(input: &'_ str) -> parse::Result<InputPart2<'_>> {
+    parse_input_part1(input)
+}
+
+type InputPart3<'a> = InputPart1<'a>;
+fn parse_input_part3(input: &'_ str) -> parse::Result<InputPart3<'_>> {
+    parse_input_part1(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_p1() -> anyhow::Result<()> {
+        let mut input = parse_input_part1("x=1 y=2\nx=2 y=3\nx=3 y=4\nx=4 y=4")?;
+        assert_eq!(p1(&mut input), 1310);
+        Ok(())
+    }
+
+    #[test]
+    fn test_p2() -> anyhow::Result<()> {
+        let mut input = parse_input_part1("x=12 y=2\nx=8 y=4\nx=7 y=1\nx=1 y=5\nx=1 y=3")?;
+        assert_eq!(p2(&mut input), 14);
+
+        // One is at the top every odd day and the other every fourth.
+        let mut input = parse_input_part1("x=1 y=2\nx=4 y=1")?;
+        assert_eq!(p2(&mut input), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_p2_big() -> anyhow::Result<()> {
+        let mut input = parse_input_part1("x=3 y=1\nx=3 y=9\nx=1 y=5\nx=4 y=10\nx=5 y=3")?;
+        assert_eq!(p2(&mut input), 13659);
+        Ok(())
+    }
+
+    #[test]
+    fn test_p3() -> anyhow::Result<()> {
+        let mut input = parse_input_part1("x=3 y=1\nx=3 y=9\nx=1 y=5\nx=4 y=10\nx=5 y=3")?;
+        assert_eq!(p2(&mut input), 13659);
+        Ok(())
+    }
+}
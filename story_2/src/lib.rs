@@ -0,0 +1,12 @@
+//! Solutions for [story 2](https://everybody.codes/story/2/quests).
+
+use ec_common::Entry;
+
+mod quest01;
+
+pub const EVENT: u16 = 2;
+
+/// All of the quests solved so far.
+pub fn quests() -> Vec<Entry> {
+    vec![quest01::ENTRY]
+}
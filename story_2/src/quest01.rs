@@ -1,16 +1,17 @@
-use std::time::Instant;
+use std::fmt::Display;
 
-use rustc_hash::FxHashMap;
+use ec_common::{
+    Entry, Solution,
+    assignment::{Assignment, max_weight, min_cost},
+    parse,
+};
 
-const INPUT_PART1: &str = include_str!("inputs/quest01-1.txt");
-const INPUT_PART2: &str = include_str!("inputs/quest01-2.txt");
-const INPUT_PART3: &str = include_str!("inputs/quest01-3.txt");
-
-fn parse(input: &str) -> (Vec<Vec<char>>, Vec<Vec<char>>) {
-    let (grid, moves) = input.split_once("\n\n").unwrap();
+type Input = (Vec<Vec<char>>, Vec<Vec<char>>);
+fn parse(input: &str) -> parse::Result<Input> {
+    let (grid, moves) = parse::split_once(input, input, "\n\n")?;
     let grid = grid.lines().map(|l| l.chars().collect()).collect();
     let moves = moves.lines().map(|l| l.chars().collect()).collect();
-    (grid, moves)
+    Ok((grid, moves))
 }
 
 // Calculate score for a given start position and it's moves.
@@ -43,9 +44,7 @@ fn score(grid: &[Vec<char>], moves: &[char], start: usize) -> usize {
     ((col / 2 + 1) * 2).saturating_sub(start / 2 + 1)
 }
 
-fn p1(input: &str) -> usize {
-    let (grid, moves) = parse(input);
-
+fn p1((grid, moves): &Input) -> usize {
     let starts = grid[0]
         .iter()
         .enumerate()
@@ -57,13 +56,11 @@ fn p1(input: &str) -> usize {
     starts
         .iter()
         .zip(moves.iter())
-        .map(|(start, moves)| score(&grid, moves, *start))
+        .map(|(start, moves)| score(grid, moves, *start))
         .sum()
 }
 
-fn p2(input: &str) -> usize {
-    let (grid, moves) = parse(input);
-
+fn p2((grid, moves): &Input) -> usize {
     let starts = grid[0]
         .iter()
         .enumerate()
@@ -77,16 +74,16 @@ fn p2(input: &str) -> usize {
         .map(|moves| {
             starts
                 .iter()
-                .map(|start| score(&grid, moves, *start))
+                .map(|start| score(grid, moves, *start))
                 .max()
                 .unwrap()
         })
         .sum()
 }
 
-fn p3(input: &str) -> String {
-    let (grid, moves) = parse(input);
-
+// P3 is an assignment problem: each token goes in its own slot and we want the lowest and highest
+// total score. Returns the best (min, max) assignments of tokens to slots.
+fn assignments((grid, moves): &Input) -> (Assignment, Assignment) {
     let starts = grid[0]
         .iter()
         .enumerate()
@@ -99,83 +96,53 @@ fn p3(input: &str) -> String {
         .map(|moves| {
             starts
                 .iter()
-                .map(|start| score(&grid, moves, *start))
+                .map(|start| score(grid, moves, *start) as i64)
                 .collect()
         })
         .collect::<Vec<Vec<_>>>();
 
-    let m = dp(
-        &scores,
-        0,
-        0,
-        &mut FxHashMap::default(),
-        usize::MAX,
-        std::cmp::min,
-    );
-
-    let n = dp(
-        &scores,
-        0,
-        0,
-        &mut FxHashMap::default(),
-        usize::MIN,
-        std::cmp::max,
-    );
-
-    format!("{m} {n}")
+    (min_cost(&scores), max_weight(&scores))
 }
 
-// P3 is an assignment problem. We can use dynamic programming to solve. TODO describe state, memo,
-// recurrence relation. The gist is that if we have seen the same 3 open slots and the same 3 open
-// tokens, we don't need to calculate it again. Instead, we can just reuse it. So we sort of "try
-// all" but there is a lot of overlap in the state graph.
-fn dp<F>(
-    scores: &[Vec<usize>],
-    cur: usize,
-    used: usize,
-    memo: &mut FxHashMap<(usize, usize), usize>,
-    initial: usize,
-    cmp: F,
-) -> usize
-where
-    F: Fn(usize, usize) -> usize + Copy,
-{
-    if cur == scores.len() {
-        return 0;
-    }
-    if let Some(&val) = memo.get(&(cur, used)) {
-        return val;
-    }
+fn p3(input: &Input) -> String {
+    let (min, max) = assignments(input);
+    format!("{} {}", min.total, max.total)
+}
 
-    let mut m = initial;
-    let mut available = !used & ((1 << scores[cur].len()) - 1);
-    while available > 0 {
-        let slot = available.trailing_zeros() as usize;
+struct Quest;
 
-        let res = scores[cur][slot] + dp(scores, cur + 1, used | (1 << slot), memo, initial, cmp);
-        m = cmp(m, res);
+impl Solution for Quest {
+    type Part1<'a> = Input;
+    type Part2<'a> = Input;
+    type Part3<'a> = Input;
 
-        available &= available - 1; // Clear the LSB
+    fn parse_part1(input: &str) -> anyhow::Result<Self::Part1<'_>> {
+        Ok(parse(input)?)
     }
 
-    memo.insert((cur, used), m);
-    m
-}
+    fn parse_part2(input: &str) -> anyhow::Result<Self::Part2<'_>> {
+        Ok(parse(input)?)
+    }
+
+    fn parse_part3(input: &str) -> anyhow::Result<Self::Part3<'_>> {
+        Ok(parse(input)?)
+    }
 
-fn main() {
-    let now = Instant::now();
-    let solution = p1(INPUT_PART1);
-    println!("p1 {:?} {}", now.elapsed(), solution);
+    fn p1(input: &mut Self::Part1<'_>) -> impl Display {
+        p1(input)
+    }
 
-    let now = Instant::now();
-    let solution = p2(INPUT_PART2);
-    println!("p2 {:?} {}", now.elapsed(), solution);
+    fn p2(input: &mut Self::Part2<'_>) -> impl Display {
+        p2(input)
+    }
 
-    let now = Instant::now();
-    let solution = p3(INPUT_PART3);
-    println!("p3 {:?} {}", now.elapsed(), solution);
+    fn p3(input: &mut Self::Part3<'_>) -> impl Display {
+        p3(input)
+    }
 }
 
+pub(crate) const ENTRY: Entry = Entry::new::<Quest>(crate::EVENT, 1);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -183,30 +150,41 @@ mod tests {
     #[test]
     fn test_p1() {
         let input = "*.*.*.*.*.*.*.*.*\n.*.*.*.*.*.*.*.*.\n*.*.*...*.*...*..\n.*.*.*.*.*...*.*.\n*.*.....*...*.*.*\n.*.*.*.*.*.*.*.*.\n*...*...*.*.*.*.*\n.*.*.*.*.*.*.*.*.\n*.*.*...*.*.*.*.*\n.*...*...*.*.*.*.\n*.*.*.*.*.*.*.*.*\n.*.*.*.*.*.*.*.*.\n\nRRRLRLRRRRRL\nLLLLRLRRRRRR\nRLLLLLRLRLRL\nLRLLLRRRLRLR\nLLRLLRLLLRRL\nLRLRLLLRRRRL\nLRLLLLLLRLLL\nRRLLLRLLRLRR\nRLLLLLRLLLRL";
-        assert_eq!(p1(input), 26);
+        assert_eq!(p1(&parse(input).unwrap()), 26);
     }
 
     #[test]
     fn test_p2() {
         let input = "*.*.*.*.*.*.*.*.*.*.*.*.*\n.*.*.*.*.*.*.*.*.*.*.*.*.\n..*.*.*.*...*.*...*.*.*..\n.*...*.*.*.*.*.*.....*.*.\n*.*...*.*.*.*.*.*...*.*.*\n.*.*.*.*.*.*.*.*.......*.\n*.*.*.*.*.*.*.*.*.*...*..\n.*.*.*.*.*.*.*.*.....*.*.\n*.*...*.*.*.*.*.*.*.*....\n.*.*.*.*.*.*.*.*.*.*.*.*.\n*.*.*.*.*.*.*.*.*.*.*.*.*\n.*.*.*.*.*.*.*.*.*...*.*.\n*.*.*.*.*.*.*.*.*...*.*.*\n.*.*.*.*.*.*.*.*.....*.*.\n*.*.*.*.*.*.*.*...*...*.*\n.*.*.*.*.*.*.*.*.*.*.*.*.\n*.*.*...*.*.*.*.*.*.*.*.*\n.*...*.*.*.*...*.*.*...*.\n*.*.*.*.*.*.*.*.*.*.*.*.*\n.*.*.*.*.*.*.*.*.*.*.*.*.\n\nRRRLLRRRLLRLRRLLLRLR\nRRRRRRRRRRLRRRRRLLRR\nLLLLLLLLRLRRLLRRLRLL\nRRRLLRRRLLRLLRLLLRRL\nRLRLLLRRLRRRLRRLRRRL\nLLLLLLLLRLLRRLLRLLLL\nLRLLRRLRLLLLLLLRLRRL\nLRLLRRLLLRRRRRLRRLRR\nLRLLRRLRLLRLRRLLLRLL\nRLLRRRRLRLRLRLRLLRRL";
-        assert_eq!(p2(input), 115);
+        assert_eq!(p2(&parse(input).unwrap()), 115);
     }
 
     #[test]
     fn test_p3() {
         let input = "*.*.*.*.*.*.*.*.*\n.*.*.*.*.*.*.*.*.\n*.*.*...*.*...*..\n.*.*.*.*.*...*.*.\n*.*.....*...*.*.*\n.*.*.*.*.*.*.*.*.\n*...*...*.*.*.*.*\n.*.*.*.*.*.*.*.*.\n*.*.*...*.*.*.*.*\n.*...*...*.*.*.*.\n*.*.*.*.*.*.*.*.*\n.*.*.*.*.*.*.*.*.\n\nRRRLRLRRRRRL\nLLLLRLRRRRRR\nRLLLLLRLRLRL\nLRLLLRRRLRLR\nLLRLLRLLLRRL\nLRLRLLLRRRRL";
-        assert_eq!(p3(input), "13 43");
+        assert_eq!(p3(&parse(input).unwrap()), "13 43");
+    }
+
+    #[test]
+    fn test_assignments() {
+        let input = "*.*.*.*.*.*.*.*.*\n.*.*.*.*.*.*.*.*.\n*.*.*...*.*...*..\n.*.*.*.*.*...*.*.\n*.*.....*...*.*.*\n.*.*.*.*.*.*.*.*.\n*...*...*.*.*.*.*\n.*.*.*.*.*.*.*.*.\n*.*.*...*.*.*.*.*\n.*...*...*.*.*.*.\n*.*.*.*.*.*.*.*.*\n.*.*.*.*.*.*.*.*.\n\nRRRLRLRRRRRL\nLLLLRLRRRRRR\nRLLLLLRLRLRL\nLRLLLRRRLRLR\nLLRLLRLLLRRL\nLRLRLLLRRRRL";
+        let (min, max) = assignments(&parse(input).unwrap());
+        assert_eq!((min.total, max.total), (13, 43));
+
+        // The slot each token was dropped in.
+        assert_eq!(min.columns, [0, 4, 1, 3, 2, 5]);
+        assert_eq!(max.columns, [5, 6, 3, 8, 0, 7]);
     }
 
     #[test]
     fn test_p3_bigger() {
         let input = "*.*.*.*.*.*.*.*.*.*.*.*.*\n.*.*.*.*.*.*.*.*.*.*.*.*.\n..*.*.*.*...*.*...*.*.*..\n.*...*.*.*.*.*.*.....*.*.\n*.*...*.*.*.*.*.*...*.*.*\n.*.*.*.*.*.*.*.*.......*.\n*.*.*.*.*.*.*.*.*.*...*..\n.*.*.*.*.*.*.*.*.....*.*.\n*.*...*.*.*.*.*.*.*.*....\n.*.*.*.*.*.*.*.*.*.*.*.*.\n*.*.*.*.*.*.*.*.*.*.*.*.*\n.*.*.*.*.*.*.*.*.*...*.*.\n*.*.*.*.*.*.*.*.*...*.*.*\n.*.*.*.*.*.*.*.*.....*.*.\n*.*.*.*.*.*.*.*...*...*.*\n.*.*.*.*.*.*.*.*.*.*.*.*.\n*.*.*...*.*.*.*.*.*.*.*.*\n.*...*.*.*.*...*.*.*...*.\n*.*.*.*.*.*.*.*.*.*.*.*.*\n.*.*.*.*.*.*.*.*.*.*.*.*.\n\nRRRLLRRRLLRLRRLLLRLR\nRRRRRRRRRRLRRRRRLLRR\nLLLLLLLLRLRRLLRRLRLL\nRRRLLRRRLLRLLRLLLRRL\nRLRLLLRRLRRRLRRLRRRL\nLLLLLLLLRLLRRLLRLLLL";
-        assert_eq!(p3(input), "25 66");
+        assert_eq!(p3(&parse(input).unwrap()), "25 66");
     }
 
     #[test]
     fn test_p3_biggest() {
         let input = "*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*\n.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.\n..*.*.*.*.*.*.........*.*.*.*.....*.*.*\n.*.*...*.*.*.*.*.*.*.*.*.*.*...*.*.*.*.\n*.*.*.*...*.*.*.*.*.....*.*.*.*...*.*..\n.*...*.*...*.*.*.*.*.*.*.....*.*.*.*.*.\n*.*.*.*.*.....*.*.*.*.*.*.*.*.*.*.*.*.*\n.*.*.*.*.*.*...*.*.*.*.....*.*.*.*...*.\n*.*...*.*.*.*.*.*.*.*...*.*.*...*.*.*.*\n.*...*.*.*.*.*.*.*.*...*.*.*.*.*.*.*.*.\n*.*.*.*.*.*...*.....*.*...*...*.*.*.*.*\n.*...*.*.*.*.*...*.*.*.*.*...*.*...*.*.\n*.*.*.*.*...*.*.*.*.*.*.*.*...*.*.*.*.*\n.*.*.*.*.*.*.*.*...*.*.*.*.*.*.*.*.*.*.\n....*.*.*.*...*.*.*.*.*.*.*...*.*.*...*\n.*.*.*...*.*.*.*.*...*.*.*.*.*.*.*.*...\n*.*.*.*.*.*.*.....*...*...*.*.*.*.*.*.*\n.*.*...*.....*.*.*.*.*.*.*...*.*.*.*.*.\n*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*\n.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.*.\n\nRRRRLLRRLLLLLLLRLLRL\nRRRRRRRLRRLRRLRRRLRR\nRRRLLRRRRRLRRRRRLRRR\nLLLLRRLLRRLLLLLRRLLL\nLRRRRLRRLRLLRLLRRLRR\nRRRRRRRRLRRRRLLRRRLR";
-        assert_eq!(p3(input), "39 122");
+        assert_eq!(p3(&parse(input).unwrap()), "39 122");
     }
 }
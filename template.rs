@@ -1,62 +1,77 @@
-use std::time::Instant;
+use std::fmt::Display;
 
-const INPUT_PART1: &str = include_str!("inputs/[NAME]-1.txt");
-const INPUT_PART2: &str = include_str!("inputs/[NAME]-2.txt");
-const INPUT_PART3: &str = include_str!("inputs/[NAME]-3.txt");
+use ec_common::{Entry, Solution};
 
 fn parse(input: &str) -> Vec<Vec<char>> {
     // TODO: have you trim today?
     input.trim().lines().map(|l| l.chars().collect()).collect()
 }
 
-fn p1(input: &str) -> usize {
-    let input = parse(input);
+fn p1(input: &[Vec<char>]) -> usize {
     input.len()
 }
 
-fn p2(input: &str) -> usize {
-    let input = parse(input);
+fn p2(input: &[Vec<char>]) -> usize {
     input.len()
 }
 
-fn p3(input: &str) -> usize {
-    let input = parse(input);
+fn p3(input: &[Vec<char>]) -> usize {
     input.len()
 }
 
-fn main() {
-    let now = Instant::now();
-    let solution = p1(INPUT_PART1);
-    println!("p1 {:?} {}", now.elapsed(), solution);
+struct Quest;
 
-    let now = Instant::now();
-    let solution = p2(INPUT_PART2);
-    println!("p2 {:?} {}", now.elapsed(), solution);
+impl Solution for Quest {
+    type Part1<'a> = Vec<Vec<char>>;
+    type Part2<'a> = Vec<Vec<char>>;
+    type Part3<'a> = Vec<Vec<char>>;
 
-    let now = Instant::now();
-    let solution = p3(INPUT_PART3);
-    println!("p3 {:?} {}", now.elapsed(), solution);
+    fn parse_part1(input: &str) -> anyhow::Result<Self::Part1<'_>> {
+        Ok(parse(input))
+    }
+
+    fn parse_part2(input: &str) -> anyhow::Result<Self::Part2<'_>> {
+        Ok(parse(input))
+    }
+
+    fn parse_part3(input: &str) -> anyhow::Result<Self::Part3<'_>> {
+        Ok(parse(input))
+    }
+
+    fn p1(input: &mut Self::Part1<'_>) -> impl Display {
+        p1(input)
+    }
+
+    fn p2(input: &mut Self::Part2<'_>) -> impl Display {
+        p2(input)
+    }
+
+    fn p3(input: &mut Self::Part3<'_>) -> impl Display {
+        p3(input)
+    }
 }
 
+pub(crate) const ENTRY: Entry = Entry::new::<Quest>(crate::EVENT, [QUEST]);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_p1() {
-        let input = "123\n456\n789\n";
-        assert_eq!(p1(input), 3);
+        let input = parse("123\n456\n789\n");
+        assert_eq!(p1(&input), 3);
     }
 
     #[test]
     fn test_p2() {
-        let input = "123\n456\n789\n";
-        assert_eq!(p2(input), 3);
+        let input = parse("123\n456\n789\n");
+        assert_eq!(p2(&input), 3);
     }
 
     #[test]
     fn test_p3() {
-        let input = "123\n456\n789\n";
-        assert_eq!(p3(input), 3);
+        let input = parse("123\n456\n789\n");
+        assert_eq!(p3(&input), 3);
     }
 }